@@ -0,0 +1,141 @@
+use crate::{
+    Mat, Nrml, Pnt, Rig, Sim, Vect,
+    ops::{Apl, BefAft},
+    rotor::RotDim,
+    traits::Field,
+};
+
+/// A linear map followed by a translation.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Aff<const N: usize, S: Field> {
+    pub linear: Mat<N, N, S>,
+    pub trans: Vect<N, S>,
+}
+
+impl<const N: usize, S: Field> Default for Aff<N, S> {
+    fn default() -> Self {
+        Self::IDENT
+    }
+}
+
+impl<const N: usize, S: Field> Aff<N, S> {
+    pub const IDENT: Self = Self {
+        linear: Mat::IDENT,
+        trans: Vect::ZERO,
+    };
+
+    pub fn new(linear: Mat<N, N, S>, trans: Vect<N, S>) -> Self {
+        Self { linear, trans }
+    }
+
+    pub fn linear(linear: Mat<N, N, S>) -> Self {
+        Self {
+            linear,
+            ..Self::IDENT
+        }
+    }
+
+    pub fn trans(trans: Vect<N, S>) -> Self {
+        Self {
+            trans,
+            ..Self::IDENT
+        }
+    }
+
+    /// Only the linear part needs inverting, which is much cheaper than inverting the homogeneous matrix.
+    ///
+    /// `None` when the linear part is singular.
+    pub fn inv(self) -> Option<Self> {
+        let linear = self.linear.try_inverse()?;
+        Some(Self {
+            linear,
+            trans: -(linear * self.trans),
+        })
+    }
+}
+
+impl<const N: usize, S: Field> From<Rig<N, S>> for Aff<N, S>
+where
+    (): RotDim<N>,
+{
+    fn from(value: Rig<N, S>) -> Self {
+        Self::new(value.rot.mat(), value.trans)
+    }
+}
+
+impl<const N: usize, S: Field> From<Sim<N, S>> for Aff<N, S>
+where
+    (): RotDim<N>,
+{
+    fn from(value: Sim<N, S>) -> Self {
+        Self::new(value.rot.mat() * value.scale, value.trans)
+    }
+}
+
+/// Multiplies by the linear part alone.
+impl<const N: usize, S: Field> Apl<Vect<N, S>> for Aff<N, S> {
+    type Output = Vect<N, S>;
+
+    fn apl(self, other: Vect<N, S>) -> Vect<N, S> {
+        self.linear * other
+    }
+}
+
+impl<const N: usize, S: Field> Apl<Pnt<N, S>> for Aff<N, S> {
+    type Output = Pnt<N, S>;
+
+    fn apl(self, other: Pnt<N, S>) -> Pnt<N, S> {
+        Pnt::ORIGIN + self.linear * (other - Pnt::ORIGIN) + self.trans
+    }
+}
+
+/// Normals are carried by the inverse transpose of the linear part, so they stay perpendicular to surfaces.
+/// The translation doesn't affect them.
+///
+/// `None` when the linear part is singular.
+impl<const N: usize, S: Field> Apl<Nrml<N, S>> for Aff<N, S> {
+    type Output = Option<Nrml<N, S>>;
+
+    fn apl(self, other: Nrml<N, S>) -> Option<Nrml<N, S>> {
+        (Vect::from(other) * self.linear.try_inverse()?).normal()
+    }
+}
+
+impl<const N: usize, S: Field> BefAft for Aff<N, S> {
+    fn aft(self, other: Self) -> Self {
+        Self {
+            linear: self.linear * other.linear,
+            trans: self.linear * other.trans + self.trans,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::Dot;
+
+    #[test]
+    fn test_aff() {
+        let a = Aff::<3, f64>::new(
+            Mat([[2., 0.3, -1.], [0.1, 1.5, 0.2], [0.4, -0.7, 0.9]]),
+            Vect([1., 2., 3.]),
+        );
+        let b = Aff::new(
+            Mat([[1., 0.2, 0.], [0.5, 1., 0.2], [0., 0.1, 3.]]),
+            Vect([-1., 0.5, 2.]),
+        );
+        let p = Pnt([0.4, -2., 1.3]);
+        assert!((a.aft(b).apl(p) - a.apl(b.apl(p))).magn() < 1e-9);
+        assert!((a.inv().unwrap().apl(a.apl(p)) - p).magn() < 1e-9);
+
+        let n = Vect([1., 1., 0.]).normal().unwrap();
+        let tangent = Vect([1., -1., 0.5]);
+        assert!(a.apl(n).unwrap().dot(a.linear * tangent).abs() < 1e-9);
+
+        let singular = Aff::<2, f64>::linear(Mat([[1., 2.], [2., 4.]]));
+        assert!(singular.inv().is_none());
+        assert!(singular.apl(Nrml::axis(0)).is_none());
+        assert!(singular.apl(Nrml::axis(1)).is_none());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    Pnt, Rig, Rot, Vect,
+    ops::{Apl, BefAft, Cross, Dot},
+    traits::Field,
+};
+
+/// A quaternion as its scalar and bivector parts, not necessarily normalized.
+type Quat<S> = (S, Vect<3, S>);
+
+fn mul<S: Field>((w1, a): Quat<S>, (w2, b): Quat<S>) -> Quat<S> {
+    (w1.mul(w2).sub(a.dot(b)), a.cross(b) + b * w1 + a * w2)
+}
+
+fn conj<S: Field>((w, bi): Quat<S>) -> Quat<S> {
+    (w, -bi)
+}
+
+fn dot<S: Field>((w1, a): Quat<S>, (w2, b): Quat<S>) -> S {
+    w1.mul(w2).add(a.dot(b))
+}
+
+fn scale<S: Field>((w, bi): Quat<S>, s: S) -> Quat<S> {
+    (w.mul(s), bi * s)
+}
+
+fn add<S: Field>((w1, a): Quat<S>, (w2, b): Quat<S>) -> Quat<S> {
+    (w1.add(w2), a + b)
+}
+
+/// A unit dual quaternion, representing the same motions as a [`Rig<3, S>`].
+///
+/// The real part is the rotor, and the dual part is half the translation times the rotor.
+/// Unlike the fields of a `Rig`, both parts can be summed and renormalized together,
+/// which blends rotation and translation coherently.
+#[derive(Copy, Clone)]
+pub struct DualRot<S: Field> {
+    real: Quat<S>,
+    dual: Quat<S>,
+}
+
+impl<S: Field> Default for DualRot<S> {
+    fn default() -> Self {
+        Self::IDENT
+    }
+}
+
+impl<S: Field> DualRot<S> {
+    pub const IDENT: Self = Self {
+        real: (S::ONE, Vect::ZERO),
+        dual: (S::ZERO, Vect::ZERO),
+    };
+
+    pub fn rot(self) -> Rot<3, S> {
+        let (w, bi) = self.real;
+        unsafe { Rot::from_w_bi_unchecked(w, bi) }
+    }
+
+    pub fn trans(self) -> Vect<3, S> {
+        mul(scale(self.dual, S::TWO), conj(self.real)).1
+    }
+
+    pub fn inv(self) -> Self {
+        Self {
+            real: conj(self.real),
+            dual: conj(self.dual),
+        }
+    }
+
+    /// Makes the real part unit and the dual part orthogonal to it.
+    fn normalize(self) -> Self {
+        let magn = dot(self.real, self.real).sqrt();
+        if magn.is_zero() {
+            return Self::IDENT;
+        }
+        let real = scale(self.real, S::ONE.div(magn));
+        let dual = scale(self.dual, S::ONE.div(magn));
+        Self {
+            real,
+            dual: add(dual, scale(real, dot(real, dual).neg())),
+        }
+    }
+
+    /// Interpolates from `self` to `other` along the screw motion between them, at a constant rate.
+    /// This takes the short way around.
+    pub fn sclerp(self, other: Self, t: S) -> Self {
+        Rig::from(self).sclerp(other.into(), t).into()
+    }
+
+    /// The weighted blend of `terms`, by summing them and renormalizing.
+    ///
+    /// Each term is first flipped to the same side as the first one, so that the blend takes the short way around.
+    /// It is cheaper than interpolating, and close to it when the terms are near each other.
+    ///
+    /// Blending no terms doesn't compile, since there is no motion for it to give.
+    pub fn blend<const L: usize>(terms: [(Self, S); L]) -> Self {
+        const { assert!(L > 0, "blending needs at least one term") }
+        let (first, weight) = terms[0];
+        let mut sum = Self {
+            real: scale(first.real, weight),
+            dual: scale(first.dual, weight),
+        };
+        for &(term, weight) in &terms[1..] {
+            let weight = if dot(first.real, term.real) < S::ZERO {
+                weight.neg()
+            } else {
+                weight
+            };
+            sum.real = add(sum.real, scale(term.real, weight));
+            sum.dual = add(sum.dual, scale(term.dual, weight));
+        }
+        sum.normalize()
+    }
+}
+
+impl<S: Field> From<Rig<3, S>> for DualRot<S> {
+    fn from(value: Rig<3, S>) -> Self {
+        let real = (value.rot.w(), value.rot.bi());
+        Self {
+            real,
+            dual: scale(mul((S::ZERO, value.trans), real), S::HALF),
+        }
+    }
+}
+
+impl<S: Field> From<DualRot<S>> for Rig<3, S> {
+    fn from(value: DualRot<S>) -> Self {
+        Self {
+            trans: value.trans(),
+            rot: value.rot(),
+        }
+    }
+}
+
+impl<S: Field> BefAft for DualRot<S> {
+    fn aft(self, other: Self) -> Self {
+        Self {
+            real: mul(self.real, other.real),
+            dual: add(mul(self.real, other.dual), mul(self.dual, other.real)),
+        }
+        .normalize()
+    }
+}
+
+/// The translation is ignored, as it is for [`Rig`].
+impl<S: Field> Apl<Vect<3, S>> for DualRot<S> {
+    type Output = Vect<3, S>;
+
+    fn apl(self, other: Vect<3, S>) -> Vect<3, S> {
+        self.rot().apl(other)
+    }
+}
+
+impl<S: Field> Apl<Pnt<3, S>> for DualRot<S> {
+    type Output = Pnt<3, S>;
+
+    fn apl(self, other: Pnt<3, S>) -> Pnt<3, S> {
+        self.rot().apl(other) + self.trans()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dual_rot() {
+        let a = Rig::<3, f64>::new(Vect([1., -2., 0.5]), Rot::from_torq(Vect([0.3, -1.2, 0.5])));
+        let b = Rig::<3, f64>::new(
+            Vect([-0.4, 0.3, 2.]),
+            Rot::from_torq(Vect([-1.1, 0.2, 0.8])),
+        );
+        let p = Pnt([0.4, -2., 1.3]);
+        let (da, db) = (DualRot::from(a), DualRot::from(b));
+        assert!((da.apl(p) - a.apl(p)).magn() < 1e-9);
+        assert!((da.aft(db).apl(p) - a.aft(b).apl(p)).magn() < 1e-9);
+        assert!((da.inv().apl(da.apl(p)) - p).magn() < 1e-9);
+
+        let half = da.sclerp(db, 0.5);
+        assert!((DualRot::blend([(da, 0.5), (db, 0.5)]).apl(p) - half.apl(p)).magn() < 1e-9);
+        assert!((da.sclerp(db, 1.).apl(p) - b.apl(p)).magn() < 1e-9);
+    }
+}
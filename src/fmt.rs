@@ -3,7 +3,8 @@ use std::{fmt::*, ops::Index};
 use culit::culit;
 
 use crate::{
-    Mat, Nrml, Rig, Rot, Vect,
+    Mat, Multivector, Nrml, Pnt, Rig, Rot, Vect,
+    multivector::CliffordDim,
     op_wrapper::{Sc, scs},
     rotor::{Axis, Bivector, RotDim},
     traits::{Field, Ring},
@@ -25,6 +26,23 @@ impl<S: Ring + Debug, const N: usize> Debug for Vect<N, S> {
     }
 }
 
+impl<S: Ring + Display, const N: usize> Display for Pnt<N, S> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str("P{")?;
+        for i in 0..N - 1 {
+            f.write_str(&format!("{}, ", self[i]))?;
+        }
+        f.write_str(&format!("{}}}", self[N - 1]))
+    }
+}
+
+impl<S: Ring + Debug, const N: usize> Debug for Pnt<N, S> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "P")?;
+        f.debug_list().entries(self.0).finish()
+    }
+}
+
 impl<S: Field + Display, const N: usize> Display for Nrml<N, S> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         f.write_str("N{")?;
@@ -42,7 +60,7 @@ impl<S: Field + Debug, const N: usize> Debug for Nrml<N, S> {
     }
 }
 
-impl<S: Field + Display, const N: usize, const M: usize> Display for Mat<N, M, S> {
+impl<S: Ring + Display, const N: usize, const M: usize> Display for Mat<N, M, S> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         if N == 0 {
             f.write_str("[]")?
@@ -63,6 +81,45 @@ impl<S: Field + Display, const N: usize, const M: usize> Display for Mat<N, M, S
     }
 }
 
+impl<const N: usize, S: Ring + Display> Display for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut first = true;
+        for (blade, value) in self.blades().iter().enumerate() {
+            if value.is_zero() {
+                continue;
+            }
+            if !first {
+                f.write_str(" + ")?;
+            }
+            first = false;
+            write!(f, "{value}")?;
+            if blade != 0 {
+                f.write_str("e")?;
+                for i in (0..N).filter(|i| blade & 1 << i != 0) {
+                    write!(f, "{i}")?;
+                }
+            }
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize, S: Ring + Debug> Debug for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "Multivector")?;
+        f.debug_list().entries(self.blades()).finish()
+    }
+}
+
 impl<const N: usize, S: Field + Display> Display for Rot<N, S>
 where
     (): RotDim<N>,
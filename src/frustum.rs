@@ -0,0 +1,270 @@
+use crate::{
+    DepthRange, Mat, Nrml, Pnt, Rig, Vect,
+    ops::{Apl, Dot},
+    traits::*,
+};
+
+/// The points where `normal.dot(point) + offset` is nonnegative.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Plane<S: Field> {
+    pub normal: Nrml<3, S>,
+    pub offset: S,
+}
+
+impl<S: Field> Plane<S> {
+    /// The plane of `vect.dot([x, y, z, 1]) >= 0`, or `None` if it has no direction.
+    fn from_homogeneous(vect: Vect<4, S>) -> Option<Self> {
+        let Vect([x, y, z, w]) = vect;
+        let (magn, normal) = Vect([x, y, z]).magn_normal()?;
+        Some(Self {
+            normal,
+            offset: w.div(magn),
+        })
+    }
+
+    /// How far `point` is in front of the plane, negative when behind it.
+    pub fn distance(self, point: Pnt<3, S>) -> S {
+        self.normal.dot(point.vect()).add(self.offset)
+    }
+
+    /// The same plane after moving everything by `rig`.
+    fn moved(self, rig: Rig<3, S>) -> Self {
+        let normal = rig.rot.apl(self.normal);
+        Self {
+            normal,
+            offset: self.offset.sub(normal.dot(rig.trans)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Containment {
+    Inside,
+    Outside,
+    Intersecting,
+}
+
+/// The region seen by a camera, as the planes bounding it, each facing inwards.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Frustum<S: Field> {
+    pub left: Plane<S>,
+    pub right: Plane<S>,
+    pub bottom: Plane<S>,
+    pub top: Plane<S>,
+    pub near: Plane<S>,
+    /// `None` for projections without a far plane.
+    pub far: Option<Plane<S>>,
+}
+
+impl<S: Field> Frustum<S> {
+    /// The frustum of a view-projection matrix whose clip-space depth is in `depth`.
+    ///
+    /// `None` when the matrix is degenerate.
+    pub fn from_mat(mat: Mat<4, 4, S>, depth: DepthRange) -> Option<Self> {
+        let [x, y, z, w] = mat.0.map(Vect);
+        let (near, far) = match depth {
+            DepthRange::NegOneToOne => (w + z, w - z),
+            DepthRange::ZeroToOne => (z, w - z),
+            DepthRange::OneToZero => (w - z, z),
+        };
+        Some(Self {
+            left: Plane::from_homogeneous(w + x)?,
+            right: Plane::from_homogeneous(w - x)?,
+            bottom: Plane::from_homogeneous(w + y)?,
+            top: Plane::from_homogeneous(w - y)?,
+            near: Plane::from_homogeneous(near)?,
+            far: Plane::from_homogeneous(far),
+        })
+    }
+
+    /// The frustum of [`Mat::perspective`] seen from `camera`, with no far plane if `f` is `None`.
+    pub fn perspective(camera: Rig<3, S>, aspect: S, fov: S, n: S, f: Option<S>) -> Self {
+        let vertical = fov.mul(S::HALF).tan();
+        let horizontal = vertical.mul(aspect);
+        let side = |x: S, y: S, slope: S| Plane {
+            normal: Vect([x, y, slope.neg()]).normal().unwrap(),
+            offset: S::ZERO,
+        };
+        Self {
+            left: side(S::ONE, S::ZERO, horizontal),
+            right: side(S::ONE.neg(), S::ZERO, horizontal),
+            bottom: side(S::ZERO, S::ONE, vertical),
+            top: side(S::ZERO, S::ONE.neg(), vertical),
+            near: Plane {
+                normal: -Nrml::axis(2),
+                offset: n.neg(),
+            },
+            far: f.map(|f| Plane {
+                normal: Nrml::axis(2),
+                offset: f,
+            }),
+        }
+        .moved(camera)
+    }
+
+    /// The frustum of [`Mat::orthographic`] seen from `camera`.
+    pub fn orthographic(
+        camera: Rig<3, S>,
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        n: S,
+        f: S,
+    ) -> Self {
+        let plane = |axis: usize, sign: bool, offset: S| Plane {
+            normal: if sign {
+                Nrml::axis(axis)
+            } else {
+                -Nrml::axis(axis)
+            },
+            offset,
+        };
+        Self {
+            left: plane(0, true, left.neg()),
+            right: plane(0, false, right),
+            bottom: plane(1, true, bottom.neg()),
+            top: plane(1, false, top),
+            near: plane(2, false, n.neg()),
+            far: Some(plane(2, true, f)),
+        }
+        .moved(camera)
+    }
+
+    fn moved(self, rig: Rig<3, S>) -> Self {
+        Self {
+            left: self.left.moved(rig),
+            right: self.right.moved(rig),
+            bottom: self.bottom.moved(rig),
+            top: self.top.moved(rig),
+            near: self.near.moved(rig),
+            far: self.far.map(|far| far.moved(rig)),
+        }
+    }
+
+    pub fn planes(self) -> impl Iterator<Item = Plane<S>> {
+        [self.left, self.right, self.bottom, self.top, self.near]
+            .into_iter()
+            .chain(self.far)
+    }
+
+    /// Whether each plane is passed fully, partly or not at all, given the least and greatest distance of a shape from it.
+    fn classify(self, range: impl Fn(Plane<S>) -> (S, S)) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in self.planes() {
+            let (least, greatest) = range(plane);
+            if greatest < S::ZERO {
+                return Containment::Outside;
+            }
+            if least < S::ZERO {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    /// Points on the boundary are inside.
+    pub fn point(self, point: Pnt<3, S>) -> Containment {
+        self.classify(|plane| {
+            let distance = plane.distance(point);
+            (distance, distance)
+        })
+    }
+
+    pub fn sphere(self, center: Pnt<3, S>, radius: S) -> Containment {
+        self.classify(|plane| {
+            let distance = plane.distance(center);
+            (distance.sub(radius), distance.add(radius))
+        })
+    }
+
+    /// The box from `min` to `max`, along the axes.
+    ///
+    /// This tests against each plane separately, so a box outside the frustum but near one of its edges may be reported as intersecting.
+    pub fn aabb(self, min: Pnt<3, S>, max: Pnt<3, S>) -> Containment {
+        self.classify(|plane| {
+            let (mut near, mut far) = (min, max);
+            for i in 0..3 {
+                if plane.normal[i] < S::ZERO {
+                    (near[i], far[i]) = (far[i], near[i]);
+                }
+            }
+            (plane.distance(near), plane.distance(far))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rot;
+
+    #[test]
+    fn test_frustum() {
+        let (eye, target) = (Pnt([1., 2., 3.]), Pnt([-2., 0., -1.]));
+        let camera = Rig::<3, f64>::look_at(eye, target, Nrml::axis(1));
+        // The camera looks down its negative z axis.
+        let ahead = Pnt([0., 0., -eye.distance(target)]);
+        assert!(camera.apl(ahead).distance(target) < 1e-9);
+        let view = camera.inv().to_hmat();
+        for depth in [
+            DepthRange::NegOneToOne,
+            DepthRange::ZeroToOne,
+            DepthRange::OneToZero,
+        ] {
+            let extracted =
+                Frustum::from_mat(Mat::perspective(1.5, 1., 0.1, 100., depth) * view, depth)
+                    .unwrap();
+            let built = Frustum::perspective(camera, 1.5, 1., 0.1, Some(100.));
+            for (a, b) in extracted.planes().zip(built.planes()) {
+                assert!((Vect::from(a.normal) - Vect::from(b.normal)).magn() < 1e-9);
+                assert!((a.offset - b.offset).abs() < 1e-9);
+            }
+
+            let extracted = Frustum::from_mat(
+                Mat::orthographic(-3., 5., -1., 2., 0.1, 100., depth) * view,
+                depth,
+            )
+            .unwrap();
+            let built = Frustum::orthographic(camera, -3., 5., -1., 2., 0.1, 100.);
+            for (a, b) in extracted.planes().zip(built.planes()) {
+                assert!((Vect::from(a.normal) - Vect::from(b.normal)).magn() < 1e-9);
+                assert!((a.offset - b.offset).abs() < 1e-9);
+            }
+        }
+        let infinite = Frustum::from_mat(
+            Mat::perspective_infinite(1.5, 1., 0.1, DepthRange::OneToZero),
+            DepthRange::OneToZero,
+        )
+        .unwrap();
+        assert!(infinite.far.is_none());
+
+        let frustum = Frustum::perspective(
+            Rig::rot(Rot::from_torq(Vect([0., 0.3, 0.]))),
+            1.,
+            1.,
+            1.,
+            Some(10.),
+        );
+        let ahead = Rot::from_torq(Vect([0., 0.3, 0.])).apl(Pnt([0., 0., -5.]));
+        let behind = Pnt::ORIGIN - (ahead - Pnt::ORIGIN);
+        assert_eq!(frustum.point(ahead), Containment::Inside);
+        assert_eq!(frustum.point(behind), Containment::Outside);
+        assert_eq!(frustum.sphere(ahead, 1.), Containment::Inside);
+        assert_eq!(frustum.sphere(ahead, 10.), Containment::Intersecting);
+        assert_eq!(frustum.sphere(behind, 1.), Containment::Outside);
+        let corner = Vect([1., 1., 1.]) * 0.5;
+        assert_eq!(
+            frustum.aabb(ahead - corner, ahead + corner),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.aabb(ahead - corner * 20., ahead),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            frustum.aabb(behind - corner, behind + corner),
+            Containment::Outside
+        );
+    }
+}
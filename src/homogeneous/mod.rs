@@ -1,9 +1,12 @@
 use culit::culit;
 
-use crate::{Mat, Nrml, Vect, op_wrapper::scs, traits::*};
+use crate::{Aff, Mat, Nrml, Pnt, Rig, Vect, traits::*};
 
+mod projection;
 mod vect3;
 
+pub use projection::DepthRange;
+
 impl<S: Field> Vect<4, S> {
     #[culit]
     pub fn point(position: Vect<3, S>) -> Self {
@@ -16,6 +19,23 @@ impl<S: Field> Vect<4, S> {
     }
 }
 
+impl<S: Field> From<Pnt<3, S>> for Vect<4, S> {
+    fn from(value: Pnt<3, S>) -> Self {
+        Vect::point(value.vect())
+    }
+}
+
+impl<S: Field> Pnt<3, S> {
+    /// The point that `vect` represents, or `None` if it is at infinity.
+    pub fn from_homogeneous(vect: Vect<4, S>) -> Option<Self> {
+        let Vect([x, y, z, w]) = vect;
+        if w.is_zero() {
+            return None;
+        }
+        Some(Pnt([x.div(w), y.div(w), z.div(w)]))
+    }
+}
+
 impl<S: Field> Mat<4, 4, S> {
     #[culit]
     pub fn affine(linear: Mat<3, 3, S>, translation: Vect<3, S>) -> Self {
@@ -51,17 +71,15 @@ impl<S: Field> Mat<4, 4, S> {
         ])
     }
 
-    #[culit]
+    /// The same as [`Self::perspective`] with [`DepthRange::ZeroToOne`].
     pub fn perspective_projection(aspect: S, fov: S, n: S, f: S) -> Self {
-        scs!(aspect, fov, f, n);
-        let view = (fov * 0.5Sc).tan();
-
-        Mat::from_scs([
-            [1Sc / (aspect * view), 0Sc, 0Sc, 0Sc],
-            [0Sc, 1Sc / view, 0Sc, 0Sc],
-            [0Sc, 0Sc, ((f + n) / (n - f) - 1Sc) / 2Sc, f * n / (n - f)],
-            [0Sc, 0Sc, -1Sc, 0Sc],
-        ])
+        Self::perspective(aspect, fov, n, f, DepthRange::ZeroToOne)
+    }
+
+    /// The view matrix of a camera at `eye` looking at `target`,
+    /// taking world space to the camera space that [`Self::perspective_projection`] expects.
+    pub fn look_at(eye: Pnt<3, S>, target: Pnt<3, S>, up: Nrml<3, S>) -> Self {
+        Rig::look_at(eye, target, up).inv().to_hmat()
     }
 
     #[culit]
@@ -74,3 +92,28 @@ impl<S: Field> Mat<4, 4, S> {
         ])
     }
 }
+
+impl<S: Field> Aff<2, S> {
+    #[culit]
+    pub fn to_hmat(self) -> Mat<3, 3, S> {
+        let [[xx, xy], [yx, yy]] = self.linear.0;
+        let [x, y] = self.trans.0;
+        Mat([[xx, xy, x], [yx, yy, y], [0S, 0S, 1S]])
+    }
+
+    /// The affine part of `mat`, ignoring its bottom row.
+    pub fn from_hmat(mat: Mat<3, 3, S>) -> Self {
+        Self::new(Mat::from_fn(|i, j| mat[i][j]), Vect::from_fn(|i| mat[i][2]))
+    }
+}
+
+impl<S: Field> Aff<3, S> {
+    pub fn to_hmat(self) -> Mat<4, 4, S> {
+        Mat::affine(self.linear, self.trans)
+    }
+
+    /// The affine part of `mat`, ignoring its bottom row.
+    pub fn from_hmat(mat: Mat<4, 4, S>) -> Self {
+        Self::new(Mat::from_fn(|i, j| mat[i][j]), Vect::from_fn(|i| mat[i][3]))
+    }
+}
@@ -0,0 +1,272 @@
+use culit::culit;
+
+use crate::{Mat, op_wrapper::scs, traits::*};
+
+/// Which range of clip-space depth the near and far planes are mapped to.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DepthRange {
+    /// OpenGL's convention, with the near plane at -1 and the far plane at 1.
+    NegOneToOne,
+    /// Vulkan and DirectX's convention, with the near plane at 0 and the far plane at 1.
+    ZeroToOne,
+    /// Reversed-Z, with the near plane at 1 and the far plane at 0.
+    /// Floating point depth is much more evenly precise this way around, especially with an infinite far plane.
+    OneToZero,
+}
+
+impl DepthRange {
+    /// The scale and offset taking depth in `ZeroToOne` to this range.
+    fn remap<S: Field>(self) -> (S, S) {
+        match self {
+            Self::NegOneToOne => (S::TWO, S::ONE.neg()),
+            Self::ZeroToOne => (S::ONE, S::ZERO),
+            Self::OneToZero => (S::ONE.neg(), S::ONE),
+        }
+    }
+}
+
+/// The horizontal and vertical scales of a symmetric perspective projection.
+#[culit]
+fn symmetric<S: Field>(aspect: S, fov: S) -> (S, S) {
+    scs!(aspect, fov);
+    let view = (fov * 0.5Sc).tan();
+    ((1Sc / (aspect * view)).0, (1Sc / view).0)
+}
+
+/// The scale and offset taking `[low, high]` to `[-1, 1]`.
+#[culit]
+fn extent<S: Field>(low: S, high: S) -> (S, S) {
+    scs!(low, high);
+    let size = high - low;
+    ((2Sc / size).0, (-(high + low) / size).0)
+}
+
+/// The coefficients of view depth in perspective clip depth, in `ZeroToOne`.
+fn perspective_depth<S: Field>(n: S, f: S) -> (S, S) {
+    scs!(n, f);
+    ((f / (n - f)).0, (f * n / (n - f)).0)
+}
+
+/// The limit of [`perspective_depth`] as `f` goes to infinity.
+fn infinite_depth<S: Field>(n: S) -> (S, S) {
+    (S::ONE.neg(), n.neg())
+}
+
+/// The coefficients of view depth in orthographic clip depth, in `ZeroToOne`.
+#[culit]
+fn orthographic_depth<S: Field>(n: S, f: S) -> (S, S) {
+    scs!(n, f);
+    ((1Sc / (n - f)).0, (n / (n - f)).0)
+}
+
+/// All projections here look down the negative z axis, with y up, as cameras from [`Mat::look_at`] do.
+/// Each has an analytic inverse taking clip space back to view space, which is cheaper and more precise than inverting it.
+impl<S: Field> Mat<4, 4, S> {
+    #[culit]
+    fn perspective_parts(
+        (sx, sy): (S, S),
+        (cx, cy): (S, S),
+        (a, b): (S, S),
+        depth: DepthRange,
+    ) -> Self {
+        let (scale, offset) = depth.remap::<S>();
+        Mat([
+            [sx, 0S, cx, 0S],
+            [0S, sy, cy, 0S],
+            [0S, 0S, scale.mul(a).sub(offset), scale.mul(b)],
+            [0S, 0S, 1S.neg(), 0S],
+        ])
+    }
+
+    #[culit]
+    fn perspective_parts_inverse(
+        (sx, sy): (S, S),
+        (cx, cy): (S, S),
+        (a, b): (S, S),
+        depth: DepthRange,
+    ) -> Self {
+        let (scale, offset) = depth.remap::<S>();
+        scs!(sx, sy, cx, cy, a, b, scale, offset);
+        let (a, b) = (scale * a - offset, scale * b);
+        Mat::from_scs([
+            [1Sc / sx, 0Sc, 0Sc, cx / sx],
+            [0Sc, 1Sc / sy, 0Sc, cy / sy],
+            [0Sc, 0Sc, 0Sc, -1Sc],
+            [0Sc, 0Sc, 1Sc / b, a / b],
+        ])
+    }
+
+    #[culit]
+    fn orthographic_parts(
+        (sx, sy): (S, S),
+        (tx, ty): (S, S),
+        (a, b): (S, S),
+        depth: DepthRange,
+    ) -> Self {
+        let (scale, offset) = depth.remap::<S>();
+        Mat([
+            [sx, 0S, 0S, tx],
+            [0S, sy, 0S, ty],
+            [0S, 0S, scale.mul(a), scale.mul(b).add(offset)],
+            [0S, 0S, 0S, 1S],
+        ])
+    }
+
+    #[culit]
+    fn orthographic_parts_inverse(
+        (sx, sy): (S, S),
+        (tx, ty): (S, S),
+        (a, b): (S, S),
+        depth: DepthRange,
+    ) -> Self {
+        let (scale, offset) = depth.remap::<S>();
+        scs!(sx, sy, tx, ty, a, b, scale, offset);
+        let (a, b) = (scale * a, scale * b + offset);
+        Mat::from_scs([
+            [1Sc / sx, 0Sc, 0Sc, -tx / sx],
+            [0Sc, 1Sc / sy, 0Sc, -ty / sy],
+            [0Sc, 0Sc, 1Sc / a, -b / a],
+            [0Sc, 0Sc, 0Sc, 1Sc],
+        ])
+    }
+
+    /// A symmetric perspective projection, with `fov` as the vertical field of view.
+    #[culit]
+    pub fn perspective(aspect: S, fov: S, n: S, f: S, depth: DepthRange) -> Self {
+        Self::perspective_parts(
+            symmetric(aspect, fov),
+            (0S, 0S),
+            perspective_depth(n, f),
+            depth,
+        )
+    }
+
+    #[culit]
+    pub fn perspective_inverse(aspect: S, fov: S, n: S, f: S, depth: DepthRange) -> Self {
+        Self::perspective_parts_inverse(
+            symmetric(aspect, fov),
+            (0S, 0S),
+            perspective_depth(n, f),
+            depth,
+        )
+    }
+
+    /// A symmetric perspective projection with the far plane at infinity.
+    #[culit]
+    pub fn perspective_infinite(aspect: S, fov: S, n: S, depth: DepthRange) -> Self {
+        Self::perspective_parts(symmetric(aspect, fov), (0S, 0S), infinite_depth(n), depth)
+    }
+
+    #[culit]
+    pub fn perspective_infinite_inverse(aspect: S, fov: S, n: S, depth: DepthRange) -> Self {
+        Self::perspective_parts_inverse(symmetric(aspect, fov), (0S, 0S), infinite_depth(n), depth)
+    }
+
+    /// An off-axis perspective projection, where `left`, `right`, `bottom` and `top` are the edges of the view on the near plane.
+    pub fn frustum(left: S, right: S, bottom: S, top: S, n: S, f: S, depth: DepthRange) -> Self {
+        let (sx, cx) = extent(left, right);
+        let (sy, cy) = extent(bottom, top);
+        Self::perspective_parts(
+            (sx.mul(n), sy.mul(n)),
+            (cx.neg(), cy.neg()),
+            perspective_depth(n, f),
+            depth,
+        )
+    }
+
+    pub fn frustum_inverse(
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        n: S,
+        f: S,
+        depth: DepthRange,
+    ) -> Self {
+        let (sx, cx) = extent(left, right);
+        let (sy, cy) = extent(bottom, top);
+        Self::perspective_parts_inverse(
+            (sx.mul(n), sy.mul(n)),
+            (cx.neg(), cy.neg()),
+            perspective_depth(n, f),
+            depth,
+        )
+    }
+
+    /// An orthographic projection of the box from `left` to `right`, `bottom` to `top`, and `n` to `f` in front of the camera.
+    pub fn orthographic(
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        n: S,
+        f: S,
+        depth: DepthRange,
+    ) -> Self {
+        let (sx, tx) = extent(left, right);
+        let (sy, ty) = extent(bottom, top);
+        Self::orthographic_parts((sx, sy), (tx, ty), orthographic_depth(n, f), depth)
+    }
+
+    pub fn orthographic_inverse(
+        left: S,
+        right: S,
+        bottom: S,
+        top: S,
+        n: S,
+        f: S,
+        depth: DepthRange,
+    ) -> Self {
+        let (sx, tx) = extent(left, right);
+        let (sy, ty) = extent(bottom, top);
+        Self::orthographic_parts_inverse((sx, sy), (tx, ty), orthographic_depth(n, f), depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vect;
+
+    #[test]
+    fn test_projections() {
+        let depth = |m: Mat<4, 4, f64>, z: f64| {
+            let v = m * Vect([0., 0., z, 1.]);
+            v[2] / v[3]
+        };
+        for (range, n, f) in [
+            (DepthRange::NegOneToOne, -1., 1.),
+            (DepthRange::ZeroToOne, 0., 1.),
+            (DepthRange::OneToZero, 1., 0.),
+        ] {
+            let projections = [
+                (
+                    Mat::perspective(1.5, 1., 0.1, 100., range),
+                    Mat::perspective_inverse(1.5, 1., 0.1, 100., range),
+                ),
+                (
+                    Mat::frustum(-0.3, 0.5, -0.1, 0.2, 0.1, 100., range),
+                    Mat::frustum_inverse(-0.3, 0.5, -0.1, 0.2, 0.1, 100., range),
+                ),
+                (
+                    Mat::orthographic(-3., 5., -1., 2., 0.1, 100., range),
+                    Mat::orthographic_inverse(-3., 5., -1., 2., 0.1, 100., range),
+                ),
+            ];
+            for (m, inv) in projections {
+                assert!((depth(m, -0.1) - n).abs() < 1e-9);
+                assert!((depth(m, -100.) - f).abs() < 1e-9);
+                assert!(
+                    (m * inv - Mat::IDENT)
+                        .0
+                        .iter()
+                        .flatten()
+                        .all(|x| x.abs() < 1e-9)
+                );
+            }
+            let m = Mat::perspective_infinite(1.5, 1., 0.1, range);
+            assert!((depth(m, -0.1) - n).abs() < 1e-9);
+            assert!((depth(m, -1e12) - f).abs() < 1e-9);
+        }
+    }
+}
@@ -1,9 +1,64 @@
 use lerpify::LinearCombination;
 
-use crate::{Vect, traits::Ring};
+use crate::{
+    DualRot, Nrml, Pnt, Rot, Vect,
+    rotor::RotDim,
+    traits::{Field, Ring},
+};
 
 impl<const N: usize, S: Ring> LinearCombination<S> for Vect<N, S> {
     fn linear_combination<const L: usize>(terms: [(Self, S); L]) -> Self {
         terms.into_iter().fold(Vect::ZERO, |c, n| c + (n.0 * n.1))
     }
 }
+
+impl<const N: usize, S: Ring> LinearCombination<S> for Pnt<N, S> {
+    fn linear_combination<const L: usize>(terms: [(Self, S); L]) -> Self {
+        Pnt::affine_combination(terms)
+    }
+}
+
+/// Interpolates towards each term in turn by its share of the weight so far.
+/// For two terms, this is exactly interpolating between them.
+///
+/// Blending no terms doesn't compile, since there is no direction for it to give.
+fn blend<T: Copy, S: Field, const L: usize>(
+    terms: [(T, S); L],
+    interpolate: impl Fn(T, T, S) -> T,
+) -> T {
+    const { assert!(L > 0, "blending needs at least one term") }
+    let mut total = S::ZERO;
+    let blended = terms.into_iter().fold(None, |c, (term, weight)| {
+        total.add_assign(weight);
+        match c {
+            Some(c) if !total.is_zero() => Some(interpolate(c, term, weight.div(total))),
+            _ => Some(term),
+        }
+    });
+    blended.unwrap()
+}
+
+impl<const N: usize, S: Field> LinearCombination<S> for Nrml<N, S> {
+    fn linear_combination<const L: usize>(terms: [(Self, S); L]) -> Self {
+        blend(terms, Nrml::slerp)
+    }
+}
+
+/// Blends take the short way around.
+impl<const N: usize, S: Field> LinearCombination<S> for Rot<N, S>
+where
+    (): RotDim<N>,
+{
+    fn linear_combination<const L: usize>(terms: [(Self, S); L]) -> Self {
+        blend(terms, |a, b, t| a.slerp(a.nearest(b), t))
+    }
+}
+
+/// Blends take the short way around.
+///
+/// Like the other blends, this doesn't compile for no terms.
+impl<S: Field> LinearCombination<S> for DualRot<S> {
+    fn linear_combination<const L: usize>(terms: [(Self, S); L]) -> Self {
+        DualRot::blend(terms)
+    }
+}
@@ -1,19 +1,31 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 #![warn(unused_qualifications)]
 
+pub mod affine;
 mod custom_literal;
+pub mod dual_rotor;
 mod fmt;
+pub mod frustum;
 mod homogeneous;
 #[cfg(feature = "lerpify")]
 mod lerp;
 pub mod matrix;
+pub mod multivector;
 pub mod normal;
 mod op_wrapper;
 pub mod ops;
+pub mod point;
 pub mod prelude;
 pub mod rigid;
 pub mod rotor;
+pub mod similarity;
+#[cfg(test)]
+mod test_util;
 pub mod traits;
 pub mod vector;
 
-pub use {matrix::Mat, normal::Nrml, rigid::Rig, rotor::Rot, vector::Vect};
+pub use {
+    affine::Aff, dual_rotor::DualRot, frustum::Frustum, homogeneous::DepthRange, matrix::Mat,
+    multivector::Multivector, normal::Nrml, point::Pnt, rigid::Rig, rotor::Rot, similarity::Sim,
+    vector::Vect,
+};
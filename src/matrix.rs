@@ -7,29 +7,42 @@ use crate::{
     Vect,
     op_wrapper::Sc,
     ops::{Det, Dot},
-    traits::Field,
+    traits::{Field, Ring},
 };
 
+mod cholesky;
+mod eigen;
+mod lu;
+mod polar;
+mod qr;
+mod svd;
+
+pub use cholesky::{Cholesky, Ldlt};
+pub use eigen::SymEigen;
+pub use lu::Lu;
+pub use qr::{PivotedQr, Qr};
+pub use svd::{FullSvd, Svd};
+
 #[repr(transparent)]
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "bytemuck", derive(TransparentWrapper))]
-pub struct Mat<const N: usize, const M: usize, S: Field>(pub [[S; M]; N]);
+pub struct Mat<const N: usize, const M: usize, S: Ring>(pub [[S; M]; N]);
 
 #[cfg(feature = "bytemuck")]
-unsafe impl<const N: usize, const M: usize, S: Field> Zeroable for Mat<N, M, S> where
+unsafe impl<const N: usize, const M: usize, S: Ring> Zeroable for Mat<N, M, S> where
     [[S; M]; N]: Zeroable
 {
 }
 
 #[cfg(feature = "bytemuck")]
-unsafe impl<const N: usize, const M: usize, S: Field> Pod for Mat<N, M, S> where [[S; M]; N]: Pod {}
+unsafe impl<const N: usize, const M: usize, S: Ring> Pod for Mat<N, M, S> where [[S; M]; N]: Pod {}
 
-impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Mat<N, M, S> {
     pub fn from_scs(array: [[Sc<S>; M]; N]) -> Self {
         Mat(array.map(|x| x.map(|x| x.0)))
     }
 
-    pub fn map<T: Field>(self, f: impl Fn(S) -> T) -> Mat<N, M, T> {
+    pub fn map<T: Ring>(self, f: impl Fn(S) -> T) -> Mat<N, M, T> {
         Mat(self.0.map(|x| x.map(&f)))
     }
 }
@@ -46,7 +59,7 @@ impl<const N: usize, const M: usize> Mat<N, M, f64> {
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Mat<N, M, S> {
     pub fn try_index(&self, i: usize, j: usize) -> Option<S> {
         if i > N || j > M {
             None
@@ -56,7 +69,7 @@ impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Mat<N, M, S> {
     fn row_swap_assign(&mut self, i1: usize, i2: usize) {
         (self.0[i1], self.0[i2]) = (self.0[i2], self.0[i1])
     }
@@ -96,15 +109,43 @@ impl<S: Field, const N: usize, const M1: usize, const M2: usize> AugMat<N, M1, M
     }
 }
 
+/// `n ε scale`, the size of the roundoff error from combining `n` values as large as `scale`.
+fn roundoff<S: Field>(n: usize, scale: S) -> S {
+    (0..n).fold(S::ZERO, |c, _| c.add(S::EPSILON)).mul(scale)
+}
+
+/// Why inverting a matrix failed.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Singular<S> {
+    /// The column whose pivot was smallest in magnitude.
+    pub column: usize,
+    pub pivot: S,
+    /// The reciprocal of the condition number in the 1-norm, `1 / (‖A‖₁ ‖A⁻¹‖₁)`,
+    /// which is zero when the inverse doesn't exist.
+    pub rcond: S,
+}
+
+impl<S: Ring, const N: usize, const M: usize> Mat<N, M, S> {
+    /// The largest sum of the magnitudes of a column.
+    fn norm_1(&self) -> S {
+        (0..M).fold(S::ZERO, |c, j| {
+            c.max((0..N).fold(S::ZERO, |c, i| c.add(self[i][j].abs())))
+        })
+    }
+}
+
 impl<S: Field, const N: usize> Mat<N, N, S> {
-    pub fn inverse(&self) -> Self {
+    /// Gauss–Jordan elimination with partial pivoting,
+    /// returning the column and value of the pivot smallest in magnitude.
+    fn gauss_jordan(&self) -> (Self, usize, S) {
         let mut aug = AugMat(*self, Self::IDENT);
+        let mut smallest = (0, S::INFINITY);
         for j in 0..N {
             {
-                let mut max = self[j][j].abs();
+                let mut max = aug.0[j][j].abs();
                 let mut i_max: usize = j;
                 for i in j + 1..N {
-                    let value = self[i][j].abs();
+                    let value = aug.0[i][j].abs();
                     if value > max {
                         max = value;
                         i_max = i;
@@ -114,64 +155,191 @@ impl<S: Field, const N: usize> Mat<N, N, S> {
                     aug.row_swap_assign(j, i_max);
                 }
             }
-            aug.row_div_assign(j, aug.0[j][j]);
+            let pivot = aug.0[j][j];
+            if pivot.abs() < smallest.1.abs() || pivot.is_nan() {
+                smallest = (j, pivot);
+            }
+            aug.row_div_assign(j, pivot);
             for i in j + 1..N {
                 let c = aug.0[i][j];
                 aug.row_sub_assign(i, c, j);
             }
         }
-        for i in (0..N - 1).rev() {
+        for i in (0..N).rev() {
             for j in i + 1..N {
                 aug.row_sub_assign(i, aug.0[i][j], j);
             }
         }
-        aug.1
+        (aug.1, smallest.0, smallest.1)
+    }
+
+    /// This is garbage when the matrix is singular; see [`Self::try_inverse`].
+    pub fn inverse(&self) -> Self {
+        self.gauss_jordan().0
+    }
+
+    /// `None` when the matrix is singular up to roundoff,
+    /// with the reciprocal of its condition number at most `N ε`.
+    pub fn try_inverse(&self) -> Option<Self> {
+        self.try_inverse_with_tolerance(roundoff(N, S::ONE)).ok()
+    }
+
+    /// Fails when the reciprocal of the condition number is no larger than `tolerance`,
+    /// so that the inverse would amplify relative errors in the matrix by more than `1 / tolerance`.
+    pub fn try_inverse_with_tolerance(&self, tolerance: S) -> Result<Self, Singular<S>> {
+        if N == 0 {
+            return Ok(Self::IDENT);
+        }
+        let (inverse, column, pivot) = self.gauss_jordan();
+        let finite = inverse.0.iter().flatten().all(|x| x.is_finite());
+        let norms = self.norm_1().mul(inverse.norm_1());
+        let rcond = if finite && !norms.is_zero() {
+            S::ONE.div(norms)
+        } else {
+            S::ZERO
+        };
+        if rcond.is_nan() || rcond <= tolerance {
+            Err(Singular {
+                column,
+                pivot,
+                rcond,
+            })
+        } else {
+            Ok(inverse)
+        }
     }
 }
 
-impl<S: Field> Det for Mat<1, 1, S> {
-    type Output = S;
+/// Scalars whose square matrices have determinants,
+/// by how they eliminate matrices too large for the closed forms.
+///
+/// Unsigned integers and `bool` are left out, as their subtraction can't go negative.
+///
+/// ```compile_fail
+/// use ear_algae::{Mat, ops::Det};
+/// Mat([[1u32, 2], [3, 4]]).det();
+/// ```
+pub trait DetScalar: Ring {
+    fn eliminated_det<const N: usize>(mat: Mat<N, N, Self>) -> Self;
+}
 
-    fn det(self) -> S {
-        self[0][0]
+/// By [`Lu`], whose partial pivoting keeps the products from overflowing.
+impl<S: Field> DetScalar for S {
+    fn eliminated_det<const N: usize>(mat: Mat<N, N, Self>) -> Self {
+        mat.lu().det()
     }
 }
 
-impl<S: Field> Det for Mat<2, 2, S> {
+macro_rules! signed_det_scalars {
+    ($($i:ty),*) => {
+        $(
+        /// By fraction-free elimination, which only ever divides exactly.
+        impl DetScalar for $i {
+            fn eliminated_det<const N: usize>(mat: Mat<N, N, Self>) -> Self {
+                mat.bareiss()
+            }
+        })*
+    }
+}
+
+signed_det_scalars! {i8, i16, i32, i64, i128, isize}
+
+/// Closed forms up to 4×4, and elimination as the [`DetScalar`] decides beyond that.
+///
+/// Only for floats and signed integers, not unsigned integers or `bool`.
+impl<S: DetScalar, const N: usize> Det for Mat<N, N, S> {
     type Output = S;
 
     fn det(self) -> S {
-        self[0][0].mul(self[1][1]).sub(self[0][1].mul(self[1][0]))
+        let m = |i: usize, j: usize| Sc(self[i][j]);
+        match N {
+            0 => S::ONE,
+            1 => self[0][0],
+            2 => (m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0)).0,
+            3 => {
+                let (a, b, c) = (m(0, 0), m(0, 1), m(0, 2));
+                let (d, e, f) = (m(1, 0), m(1, 1), m(1, 2));
+                let (g, h, i) = (m(2, 0), m(2, 1), m(2, 2));
+
+                //      a.mul(e.mul(i))  .add (b.mul(f.mul(g))) .add (c.mul(d.mul(h)))
+                //.sub (c.mul(e.mul(g))) .sub (b.mul(d.mul(i))) .sub (a.mul(f.mul(h)))
+
+                ((a * e * i + b * f * g + c * d * h) - (c * e * g + b * d * i + a * f * h)).0
+            }
+            4 => {
+                // Pairs the 2×2 minors of the top two rows with the complementary ones of the bottom two.
+                let top = |j: usize, k: usize| m(0, j) * m(1, k) - m(0, k) * m(1, j);
+                let bottom = |j: usize, k: usize| m(2, j) * m(3, k) - m(2, k) * m(3, j);
+                (top(0, 1) * bottom(2, 3) - top(0, 2) * bottom(1, 3)
+                    + top(0, 3) * bottom(1, 2)
+                    + top(1, 2) * bottom(0, 3)
+                    - top(1, 3) * bottom(0, 2)
+                    + top(2, 3) * bottom(0, 1))
+                .0
+            }
+            _ => S::eliminated_det(self),
+        }
     }
 }
 
-impl<S: Field> Det for Mat<3, 3, S> {
-    type Output = S;
-    fn det(self) -> S {
-        let a = Sc(self[0][0]);
-        let b = Sc(self[0][1]);
-        let c = Sc(self[0][2]);
-        let d = Sc(self[1][0]);
-        let e = Sc(self[1][1]);
-        let f = Sc(self[1][2]);
-        let g = Sc(self[2][0]);
-        let h = Sc(self[2][1]);
-        let i = Sc(self[2][2]);
+impl<S: Ring, const N: usize> Mat<N, N, S> {
+    /// Bareiss elimination, where each step's entries are 2×2 determinants
+    /// divided by the previous pivot, and the last pivot is the determinant.
+    ///
+    /// The products are formed before dividing, so this is only for integers.
+    fn bareiss(self) -> S {
+        let mut a = self;
+        let mut sign = S::ONE;
+        let mut previous = S::ONE;
+        for k in 0..N {
+            let mut max = a[k][k].abs();
+            let mut i_max = k;
+            for i in k + 1..N {
+                let value = a[i][k].abs();
+                if value > max {
+                    max = value;
+                    i_max = i;
+                }
+            }
+            if a[i_max][k].is_zero() {
+                return S::ZERO;
+            }
+            if i_max != k {
+                a.row_swap_assign(k, i_max);
+                sign = sign.neg();
+            }
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    let value = a[k][k].mul(a[i][j]).sub(a[i][k].mul(a[k][j]));
+                    a[i][j] = value.div(previous);
+                }
+            }
+            previous = a[k][k];
+        }
+        previous.mul(sign)
+    }
+}
 
-        //      a.mul(e.mul(i))  .add (b.mul(f.mul(g))) .add (c.mul(d.mul(h)))
-        //.sub (c.mul(e.mul(g))) .sub (b.mul(d.mul(i))) .sub (a.mul(f.mul(h)))
+impl<S: Field, const N: usize> Mat<N, N, S> {
+    /// The natural log of the magnitude of the determinant,
+    /// which doesn't overflow for large matrices like the determinant itself can.
+    pub fn log_abs_det(self) -> S {
+        self.lu().log_abs_det()
+    }
 
-        ((a * e * i + b * f * g + c * d * h) - (c * e * g + b * d * i + a * f * h)).0
+    /// The sign of the determinant, which is zero when the matrix is singular.
+    pub fn det_sign(self) -> S {
+        self.lu().det_sign()
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Default for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Default for Mat<N, M, S> {
     fn default() -> Self {
         Self::ZERO
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Mat<N, M, S> {
     pub fn from_fn<F: Fn(usize, usize) -> S>(f: F) -> Self {
         Mat(array::from_fn(|i| array::from_fn(|j| f(i, j))))
     }
@@ -179,7 +347,7 @@ impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
     pub const ZERO: Self = Mat([[S::ZERO; M]; N]);
 }
 
-impl<S: Field, const N: usize> Mat<N, N, S> {
+impl<S: Ring, const N: usize> Mat<N, N, S> {
     pub const IDENT: Self = {
         let mut array = [[S::ZERO; N]; N];
         let mut i = 0;
@@ -191,7 +359,7 @@ impl<S: Field, const N: usize> Mat<N, N, S> {
     };
 }
 
-impl<S: Field, const N: usize, const M: usize> Index<usize> for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Index<usize> for Mat<N, M, S> {
     type Output = [S; M];
 
     fn index(&self, i: usize) -> &Self::Output {
@@ -199,7 +367,21 @@ impl<S: Field, const N: usize, const M: usize> Index<usize> for Mat<N, M, S> {
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> IndexMut<usize> for Mat<N, M, S> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.0[i]
+    }
+}
+
+impl<S: Ring, const N: usize, const M: usize> Mat<N, M, S> {
+    pub fn from_rows(rows: [Vect<M, S>; N]) -> Self {
+        Mat(rows.map(|row| row.0))
+    }
+
+    pub fn from_cols(cols: [Vect<N, S>; M]) -> Self {
+        Mat::from_fn(|i, j| cols[j][i])
+    }
+
     pub fn row(&self, i: usize) -> Vect<M, S> {
         Vect::from_fn(|j| self[i][j])
     }
@@ -207,9 +389,60 @@ impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
     pub fn col(&self, j: usize) -> Vect<N, S> {
         Vect::from_fn(|i| self[i][j])
     }
+
+    pub fn set_row(&mut self, i: usize, row: Vect<M, S>) {
+        self[i] = row.0;
+    }
+
+    pub fn set_col(&mut self, j: usize, col: Vect<N, S>) {
+        for i in 0..N {
+            self[i][j] = col[i];
+        }
+    }
+
+    pub fn transpose(self) -> Mat<M, N, S> {
+        Mat::from_fn(|i, j| self[j][i])
+    }
+}
+
+impl<S: Ring, const N: usize> Mat<N, N, S> {
+    pub fn trace(self) -> S {
+        (0..N).fold(S::ZERO, |c, i| c.add(self[i][i]))
+    }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mul<Vect<M, S>> for Mat<N, M, S> {
+/// Like [`Det`], only for floats and signed integers.
+impl<S: DetScalar, const N: usize> Mat<N, N, S> {
+    /// The determinant of the matrix without row `i` and column `j`.
+    pub fn minor(self, i: usize, j: usize) -> S {
+        if (i + j).is_multiple_of(2) {
+            self.cofactor(i, j)
+        } else {
+            self.cofactor(i, j).neg()
+        }
+    }
+
+    /// The minor at `i`, `j`, negated when `i + j` is odd.
+    pub fn cofactor(self, i: usize, j: usize) -> S {
+        // Expanding along row `i`, which is zero but for a one at column `j`,
+        // leaves only this cofactor, and zeroing the rest of column `j` doesn't change it.
+        let mut mat = self;
+        for k in 0..N {
+            mat[k][j] = S::ZERO;
+        }
+        mat[i] = [S::ZERO; N];
+        mat[i][j] = S::ONE;
+        mat.det()
+    }
+
+    /// The transpose of the matrix of cofactors,
+    /// which is the inverse times the determinant, but exists even for singular matrices.
+    pub fn adjugate(self) -> Self {
+        Mat::from_fn(|i, j| self.cofactor(j, i))
+    }
+}
+
+impl<S: Ring, const N: usize, const M: usize> Mul<Vect<M, S>> for Mat<N, M, S> {
     type Output = Vect<N, S>;
 
     fn mul(self, vector: Vect<M, S>) -> Self::Output {
@@ -217,15 +450,15 @@ impl<S: Field, const N: usize, const M: usize> Mul<Vect<M, S>> for Mat<N, M, S>
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mul<Mat<N, M, S>> for Vect<N, S> {
-    type Output = Vect<N, S>;
+impl<S: Ring, const N: usize, const M: usize> Mul<Mat<N, M, S>> for Vect<N, S> {
+    type Output = Vect<M, S>;
 
     fn mul(self, matrix: Mat<N, M, S>) -> Self::Output {
         Vect::from_fn(|j| self.dot(matrix.col(j)))
     }
 }
 
-impl<S: Field, const N: usize, const M: usize, const P: usize> Mul<Mat<M, P, S>> for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize, const P: usize> Mul<Mat<M, P, S>> for Mat<N, M, S> {
     type Output = Mat<N, P, S>;
 
     fn mul(self, other: Mat<M, P, S>) -> Self::Output {
@@ -233,13 +466,13 @@ impl<S: Field, const N: usize, const M: usize, const P: usize> Mul<Mat<M, P, S>>
     }
 }
 
-impl<S: Field> Mat<4, 4, S> {
+impl<S: Ring> Mat<4, 4, S> {
     pub fn flatten(self) -> [S; 16] {
         array::from_fn(|i| self[i % 4][i / 4])
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Mul<S> for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Mul<S> for Mat<N, M, S> {
     type Output = Self;
 
     fn mul(self, scalar: S) -> Self::Output {
@@ -247,7 +480,7 @@ impl<S: Field, const N: usize, const M: usize> Mul<S> for Mat<N, M, S> {
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Add for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Add for Mat<N, M, S> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
@@ -255,16 +488,173 @@ impl<S: Field, const N: usize, const M: usize> Add for Mat<N, M, S> {
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Sub for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Sub for Mat<N, M, S> {
     type Output = Self;
     fn sub(self, other: Self) -> Self::Output {
         Mat::from_fn(|i, j| self[i][j].sub(other[i][j]))
     }
 }
 
-impl<S: Field, const N: usize, const M: usize> Neg for Mat<N, M, S> {
+impl<S: Ring, const N: usize, const M: usize> Neg for Mat<N, M, S> {
     type Output = Self;
     fn neg(self) -> Self::Output {
         Mat::from_fn(|i, j| self[i][j].neg())
     }
 }
+
+impl<S: Ring, const N: usize, const M: usize> AddAssign for Mat<N, M, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            for j in 0..M {
+                self[i][j].add_assign(rhs[i][j])
+            }
+        }
+    }
+}
+
+impl<S: Ring, const N: usize, const M: usize> SubAssign for Mat<N, M, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            for j in 0..M {
+                self[i][j].sub_assign(rhs[i][j])
+            }
+        }
+    }
+}
+
+impl<S: Ring, const N: usize, const M: usize> MulAssign<S> for Mat<N, M, S> {
+    fn mul_assign(&mut self, rhs: S) {
+        for i in 0..N {
+            for j in 0..M {
+                self[i][j].mul_assign(rhs)
+            }
+        }
+    }
+}
+
+impl<S: Ring, const N: usize, const M: usize> MulAssign<Mat<M, M, S>> for Mat<N, M, S> {
+    fn mul_assign(&mut self, rhs: Mat<M, M, S>) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_det() {
+        let a = Mat([
+            [0., 2., -1., 3., 1.],
+            [1., 1., 0.5, -2., 0.],
+            [4., -1., 2., 0., 2.],
+            [2., 0., 1., 1., -1.],
+            [1., 3., 0., 0.5, 2.],
+        ]);
+        let b = Mat::<4, 4, f64>::from_fn(|i, j| a[i][j]);
+        assert!(f64::abs(b.det() - b.lu().det()) < 1e-12);
+        let c = Mat::<3, 3, f64>::from_fn(|i, j| a[i][j]);
+        assert!(f64::abs(c.det() - c.lu().det()) < 1e-12);
+
+        let det = a.det();
+        assert!(f64::abs(a.log_abs_det() - det.abs().ln()) < 1e-12);
+        assert_eq!(a.det_sign(), det.signum());
+        assert_eq!(Mat([[3.]]).det(), 3.);
+
+        // Forming products before dividing would overflow these.
+        let big = Mat::<5, 5, f32>::IDENT * 1e5;
+        assert!(f32::abs(big.det() / 1e25 - 1.) < 1e-6);
+        let big = Mat::<5, 5, f64>::IDENT * 1e40;
+        assert!(f64::abs(big.det() / 1e200 - 1.) < 1e-12);
+        let small = Mat::<5, 5, f64>::from_fn(|i, j| if i == j { 1e-40 } else { 0. }) * 1.5;
+        assert!(f64::abs(small.det() / (1.5f64.powi(5) * 1e-200) - 1.) < 1e-12);
+    }
+
+    #[test]
+    fn test_ring() {
+        let a = Mat::<5, 5, i64>::from_fn(|i, j| {
+            ((i * 7 + j * 3) % 5) as i64 - 2 + (i == j) as i64 * 3
+        });
+        let det = Mat::<5, 5, f64>::from_fn(|i, j| a[i][j] as f64).det();
+        assert_eq!(a.det() as f64, det.round());
+        assert_eq!(a.adjugate() * a, Mat::IDENT * a.det());
+        assert_eq!(Mat([[2, 1], [0, 3]]) * Vect([1, -1]), Vect([1, -3]));
+
+        // Reachability in a graph with edges 0 → 1 → 2, by squaring the adjacency matrix with loops.
+        let step = Mat([
+            [true, true, false],
+            [false, true, true],
+            [false, false, true],
+        ]);
+        let reach = step * step;
+        assert!(reach[0][2] && !reach[2][0]);
+    }
+
+    #[test]
+    fn test_inverse() {
+        // The largest entry of the second column is above the first pivot row after the first swap.
+        let a = Mat([[1., 4., 0.], [2., 1., 1.], [0., 1., 3.]]);
+        let inv = a.try_inverse().unwrap();
+        assert_close(a * inv, Mat::IDENT, 1e-12);
+        assert_eq!(inv, a.inverse());
+
+        let singular = Mat([[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]);
+        assert!(singular.try_inverse().is_none());
+        // Roundoff leaves the last pivot around 1e-16, and the inverse with entries around 1e15.
+        assert!(
+            Mat([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]])
+                .try_inverse()
+                .is_none()
+        );
+        assert!(
+            Mat([[1., 1.], [1., 1. + 2. * f64::EPSILON]])
+                .try_inverse()
+                .is_none()
+        );
+        let nearly = Mat([[1., 2.], [1., 2. + 1e-12]]);
+        assert!(nearly.try_inverse().is_some());
+        let error = nearly.try_inverse_with_tolerance(1e-9).unwrap_err();
+        assert_eq!(error.column, 1);
+        assert!(error.rcond < 1e-9);
+
+        // Every pivot is one, but the inverse has entries up to 2³⁸.
+        let steep = Mat::<40, 40, f64>::from_fn(|i, j| match Ord::cmp(&i, &j) {
+            Ordering::Less => -1.,
+            Ordering::Equal => 1.,
+            Ordering::Greater => 0.,
+        });
+        let error = steep.try_inverse_with_tolerance(1e-9).unwrap_err();
+        assert_eq!(error.pivot, 1.);
+        let rcond = 1. / (steep.norm_1() * steep.inverse().norm_1());
+        assert!(error.rcond < 1e-12 && error.rcond == rcond);
+        assert!(steep.try_inverse().is_some());
+        assert!(Mat([[f64::NAN]]).try_inverse().is_none());
+        assert!(Mat::<0, 0, f64>::IDENT.try_inverse().is_some());
+    }
+
+    #[test]
+    fn test_manipulation() {
+        let mut a = Mat::from_rows([Vect([2., 0., 1.]), Vect([1., 3., -1.]), Vect([0., 1., 4.])]);
+        assert_eq!(a, Mat::from_cols([a.col(0), a.col(1), a.col(2)]));
+        assert_eq!(a.transpose().row(1), a.col(1));
+        assert_eq!(a.trace(), 9.);
+        assert_eq!(a.minor(0, 1), 4.);
+        assert_eq!(a.cofactor(0, 1), -4.);
+        assert_eq!(a * a.adjugate(), Mat::IDENT * a.det());
+        let wide = Mat([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(Vect([1., -1.]) * wide, Vect([-3., -3., -3.]));
+
+        a.set_row(2, Vect([1., 1., 1.]));
+        a.set_col(0, Vect([0., 0., 0.]));
+        a[1][1] = 5.;
+        assert_eq!(a, Mat([[0., 0., 1.], [0., 5., -1.], [0., 1., 1.]]));
+        a += Mat::IDENT;
+        a *= 2.;
+        a -= Mat::IDENT;
+        a *= Mat([[0., 1., 0.], [1., 0., 0.], [0., 0., 1.]]);
+        assert_eq!(a, Mat([[0., 1., 2.], [11., 0., -2.], [2., 0., 3.]]));
+    }
+}
@@ -0,0 +1,237 @@
+use super::*;
+
+/// The factorization of a symmetric positive definite matrix `A` into `A = L Lᵀ`,
+/// where `L` is lower triangular with a positive diagonal.
+#[derive(Copy, Clone, Debug)]
+pub struct Cholesky<const N: usize, S: Field> {
+    pub l: Mat<N, N, S>,
+}
+
+/// The factorization of a symmetric matrix `A` into `P A Pᵀ = L D Lᵀ`,
+/// where `P` permutes rows, `L` is lower triangular with ones on its diagonal, and `D` is diagonal.
+///
+/// The largest remaining diagonal entry is pivoted on at each step, which needs `A` to be semidefinite:
+/// then a zero pivot means that the rest of its row and column are zero too.
+#[derive(Copy, Clone, Debug)]
+pub struct Ldlt<const N: usize, S: Field> {
+    pub l: Mat<N, N, S>,
+    pub d: Vect<N, S>,
+    /// The row of `A` that each row of `L D Lᵀ` came from.
+    pub perm: [usize; N],
+}
+
+impl<S: Field, const N: usize> Mat<N, N, S> {
+    /// Only the lower triangle is read, assuming the matrix is symmetric.
+    ///
+    /// `None` when the matrix isn't positive definite.
+    pub fn cholesky(self) -> Option<Cholesky<N, S>> {
+        let mut l = Self::ZERO;
+        for j in 0..N {
+            let mut d = self[j][j];
+            for k in 0..j {
+                d.sub_assign(l[j][k].pow(2));
+            }
+            if d.is_nan() || d <= S::ZERO {
+                return None;
+            }
+            let d = d.sqrt();
+            l.0[j][j] = d;
+            for i in j + 1..N {
+                let mut value = self[i][j];
+                for k in 0..j {
+                    value.sub_assign(l[i][k].mul(l[j][k]));
+                }
+                l.0[i][j] = value.div(d);
+            }
+        }
+        Some(Cholesky { l })
+    }
+
+    /// The matrix is assumed to be symmetric and semidefinite.
+    ///
+    /// `None` when a zero pivot has nonzero entries below it, which only happens when the matrix is indefinite.
+    pub fn ldlt(self) -> Option<Ldlt<N, S>> {
+        let mut a = self;
+        let mut l = Self::IDENT;
+        let mut d = Vect::ZERO;
+        let mut perm = array::from_fn(|i| i);
+        for k in 0..N {
+            let mut max = a[k][k].abs();
+            let mut i_max = k;
+            for i in k + 1..N {
+                let value = a[i][i].abs();
+                if value > max {
+                    max = value;
+                    i_max = i;
+                }
+            }
+            if i_max != k {
+                a.row_swap_assign(k, i_max);
+                for i in 0..N {
+                    a.0[i].swap(k, i_max);
+                }
+                for j in 0..k {
+                    (l.0[k][j], l.0[i_max][j]) = (l[i_max][j], l[k][j]);
+                }
+                perm.swap(k, i_max);
+            }
+
+            d[k] = a[k][k];
+            if d[k].is_zero() {
+                if (k + 1..N).any(|i| !a[i][k].is_zero()) {
+                    return None;
+                }
+                continue;
+            }
+            for i in k + 1..N {
+                l.0[i][k] = a[i][k].div(d[k]);
+            }
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    a.0[i][j].sub_assign(l[i][k].mul(a[k][j]));
+                }
+            }
+        }
+        Some(Ldlt { l, d, perm })
+    }
+}
+
+/// Solves `L y = b` where `L` is lower triangular.
+fn forward<S: Field, const N: usize>(l: Mat<N, N, S>, mut y: Vect<N, S>, unit: bool) -> Vect<N, S> {
+    for i in 0..N {
+        for j in 0..i {
+            let value = l[i][j].mul(y[j]);
+            y[i].sub_assign(value);
+        }
+        if !unit {
+            y[i].div_assign(l[i][i]);
+        }
+    }
+    y
+}
+
+/// Solves `Lᵀ x = y` where `L` is lower triangular.
+fn backward<S: Field, const N: usize>(
+    l: Mat<N, N, S>,
+    mut x: Vect<N, S>,
+    unit: bool,
+) -> Vect<N, S> {
+    for i in (0..N).rev() {
+        for j in i + 1..N {
+            let value = l[j][i].mul(x[j]);
+            x[i].sub_assign(value);
+        }
+        if !unit {
+            x[i].div_assign(l[i][i]);
+        }
+    }
+    x
+}
+
+impl<S: Field, const N: usize> Cholesky<N, S> {
+    /// The `x` where `A x = b`.
+    pub fn solve(&self, b: Vect<N, S>) -> Vect<N, S> {
+        backward(self.l, forward(self.l, b, false), false)
+    }
+
+    /// The `X` where `A X = B`, solving for each column of `B`.
+    pub fn solve_mat<const M: usize>(&self, b: Mat<N, M, S>) -> Mat<N, M, S> {
+        let cols: [_; M] = array::from_fn(|j| self.solve(b.col(j)));
+        Mat::from_cols(cols)
+    }
+
+    pub fn inverse(&self) -> Mat<N, N, S> {
+        self.solve_mat(Mat::IDENT)
+    }
+
+    /// The natural log of the determinant, which is always positive for these matrices.
+    /// This doesn't overflow for large matrices like the determinant itself can.
+    pub fn log_det(&self) -> S {
+        (0..N)
+            .fold(S::ZERO, |c, i| c.add(self.l[i][i].ln()))
+            .mul(S::TWO)
+    }
+}
+
+impl<S: Field, const N: usize> Ldlt<N, S> {
+    /// Whether some pivot is zero, in which case there is no unique solution to solve for.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.d[i].is_zero())
+    }
+
+    fn substitute(&self, b: Vect<N, S>) -> Vect<N, S> {
+        let y = forward(self.l, Vect::from_fn(|i| b[self.perm[i]]), true);
+        let w = backward(self.l, Vect::from_fn(|i| y[i].div(self.d[i])), true);
+        let mut x = Vect::ZERO;
+        for i in 0..N {
+            x[self.perm[i]] = w[i];
+        }
+        x
+    }
+
+    /// The `x` where `A x = b`, or `None` if `A` is singular.
+    pub fn solve(&self, b: Vect<N, S>) -> Option<Vect<N, S>> {
+        if self.is_singular() {
+            return None;
+        }
+        Some(self.substitute(b))
+    }
+
+    /// The `X` where `A X = B`, solving for each column of `B`.
+    pub fn solve_mat<const M: usize>(&self, b: Mat<N, M, S>) -> Option<Mat<N, M, S>> {
+        if self.is_singular() {
+            return None;
+        }
+        let cols: [_; M] = array::from_fn(|j| self.substitute(b.col(j)));
+        Some(Mat::from_cols(cols))
+    }
+
+    pub fn inverse(&self) -> Option<Mat<N, N, S>> {
+        self.solve_mat(Mat::IDENT)
+    }
+
+    /// The natural log of the magnitude of the determinant, which is negative infinity when `A` is singular.
+    pub fn log_det(&self) -> S {
+        (0..N).fold(S::ZERO, |c, i| c.add(self.d[i].abs().ln()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_cholesky_ldlt() {
+        let spd = Mat([[4., 1., -1.], [1., 3., 0.5], [-1., 0.5, 2.]]);
+        let chol = spd.cholesky().unwrap();
+        assert_close(chol.l * chol.l.transpose(), spd, 1e-12);
+        assert_close(spd * chol.inverse(), Mat::IDENT, 1e-12);
+        let b = Vect([1., -2., 0.5]);
+        assert!((spd * chol.solve(b) - b).magn() < 1e-12);
+
+        let ldlt = spd.ldlt().unwrap();
+        assert!((ldlt.solve(b).unwrap() - chol.solve(b)).magn() < 1e-12);
+        assert!(f64::abs(ldlt.log_det() - chol.log_det()) < 1e-12);
+        assert!(f64::abs(chol.log_det() - spd.det().ln()) < 1e-12);
+        assert!(Mat([[1., 2.], [2., 1.]]).cholesky().is_none());
+
+        // Semidefinite, as the sum of two outer products.
+        let psd = Mat::<3, 3, f64>::from_fn(|i, j| {
+            let (u, v) = ([1., 2., -1.], [0., 1., 3.]);
+            u[i] * u[j] + v[i] * v[j]
+        });
+        let ldlt = psd.ldlt().unwrap();
+        let ldl: Mat<3, 3, f64> = ldlt.l * Mat::from_fn(|i, j| ldlt.d[i] * ldlt.l[j][i]);
+        assert_close(
+            ldl,
+            Mat::from_fn(|i, j| psd[ldlt.perm[i]][ldlt.perm[j]]),
+            1e-12,
+        );
+        assert!(ldlt.is_singular());
+
+        // Indefinite, with only zeros on the diagonal to pivot on.
+        assert!(Mat([[0., 1.], [1., 0.]]).ldlt().is_none());
+        assert!(Mat([[0., 0.], [0., 0.]]).ldlt().unwrap().is_singular());
+    }
+}
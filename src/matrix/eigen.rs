@@ -0,0 +1,126 @@
+use crate::Rot;
+
+use super::*;
+
+/// The factorization of a symmetric matrix `A` into `V diag(values) Vᵀ`,
+/// where the columns of the orthogonal matrix `V` are the eigenvectors.
+#[derive(Copy, Clone, Debug)]
+pub struct SymEigen<const N: usize, S: Field> {
+    pub values: Vect<N, S>,
+    pub vectors: Mat<N, N, S>,
+}
+
+impl<S: Field, const N: usize> Mat<N, N, S> {
+    /// The eigenvalues and eigenvectors of a symmetric matrix, by cyclic Jacobi rotations.
+    ///
+    /// They come in no particular order.
+    pub fn sym_eigen(self) -> SymEigen<N, S> {
+        let mut a = self;
+        let mut vecs = Mat::<N, N, S>::IDENT;
+        for _ in 0..64 {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in p + 1..N {
+                    let apq = a[p][q];
+                    let tiny = apq.abs().mul(S::TWO.pow(7));
+                    if a[p][p].abs().add(tiny) == a[p][p].abs()
+                        && a[q][q].abs().add(tiny) == a[q][q].abs()
+                    {
+                        a.0[p][q] = S::ZERO;
+                        a.0[q][p] = S::ZERO;
+                        continue;
+                    }
+                    rotated = true;
+                    let theta = a[q][q].sub(a[p][p]).div(apq.mul(S::TWO));
+                    let t = S::ONE.div(theta.abs().add(theta.pow(2).add(S::ONE).sqrt()));
+                    let t = if theta < S::ZERO { t.neg() } else { t };
+                    let cos = S::ONE.div(t.pow(2).add(S::ONE).sqrt());
+                    let sin = t.mul(cos);
+                    for k in 0..N {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a.0[k][p] = cos.mul(akp).sub(sin.mul(akq));
+                        a.0[k][q] = sin.mul(akp).add(cos.mul(akq));
+                    }
+                    for k in 0..N {
+                        let (apk, aqk) = (a[p][k], a[q][k]);
+                        a.0[p][k] = cos.mul(apk).sub(sin.mul(aqk));
+                        a.0[q][k] = sin.mul(apk).add(cos.mul(aqk));
+                    }
+                    for k in 0..N {
+                        let (vkp, vkq) = (vecs[k][p], vecs[k][q]);
+                        vecs.0[k][p] = cos.mul(vkp).sub(sin.mul(vkq));
+                        vecs.0[k][q] = sin.mul(vkp).add(cos.mul(vkq));
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+        SymEigen {
+            values: Vect::from_fn(|i| a[i][i]),
+            vectors: vecs,
+        }
+    }
+}
+
+impl<S: Field, const N: usize> SymEigen<N, S> {
+    fn permuted(self, order: [usize; N]) -> Self {
+        Self {
+            values: Vect::from_fn(|i| self.values[order[i]]),
+            vectors: Mat::from_fn(|i, j| self.vectors[i][order[j]]),
+        }
+    }
+
+    /// With the eigenvalues in ascending order.
+    pub fn sorted(self) -> Self {
+        let mut order: [usize; N] = array::from_fn(|i| i);
+        order.sort_by(|&i, &j| self.values[i].cmp(&self.values[j]));
+        self.permuted(order)
+    }
+
+    /// With the eigenvalues in descending order, such as for principal components.
+    pub fn sorted_descending(self) -> Self {
+        let mut order: [usize; N] = array::from_fn(|i| i);
+        order.sort_by(|&i, &j| self.values[j].cmp(&self.values[i]));
+        self.permuted(order)
+    }
+}
+
+impl<S: Field> SymEigen<3, S> {
+    /// The rotation taking each axis to the corresponding eigenvector,
+    /// flipping the last eigenvector if needed to make it a proper rotation.
+    pub fn rot(self) -> Rot<3, S> {
+        let mut vectors = self.vectors;
+        if vectors.det() < S::ZERO {
+            for i in 0..3 {
+                vectors.0[i][2] = vectors[i][2].neg();
+            }
+        }
+        Rot::from_mat(vectors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_sym_eigen() {
+        let a = Mat([[4., 1., -1.], [1., 3., 0.5], [-1., 0.5, 2.]]);
+        let eigen = a.sym_eigen().sorted();
+        assert!(eigen.values[0] <= eigen.values[1] && eigen.values[1] <= eigen.values[2]);
+        for i in 0..3 {
+            let v = eigen.vectors.col(i);
+            assert!((a * v - v * eigen.values[i]).magn() < 1e-12);
+        }
+
+        let rot = eigen.sorted_descending().rot();
+        let Vect([x, y, z]) = eigen.sorted_descending().values;
+        let diag = Mat([[x, 0., 0.], [0., y, 0.], [0., 0., z]]);
+        let mat = rot.mat();
+        let rebuilt = mat * diag * mat.transpose();
+        assert_close(rebuilt, a, 1e-12);
+    }
+}
@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+/// The factorization of a square matrix `A` into `P A = L U`,
+/// where `P` permutes rows, `L` is lower triangular with ones on its diagonal, and `U` is upper triangular.
+///
+/// Rows are pivoted on the largest remaining entry of each column.
+/// The factorization always exists, but it is only usable for solving when `A` is nonsingular.
+#[derive(Copy, Clone, Debug)]
+pub struct Lu<const N: usize, S: Field> {
+    /// `L` below the diagonal and `U` on and above it.
+    lu: Mat<N, N, S>,
+    /// The row of `A` that each row of `L U` came from.
+    perm: [usize; N],
+    odd: bool,
+}
+
+impl<S: Field, const N: usize> Mat<N, N, S> {
+    pub fn lu(self) -> Lu<N, S> {
+        let mut lu = self;
+        let mut perm = array::from_fn(|i| i);
+        let mut odd = false;
+        for k in 0..N {
+            let mut max = lu[k][k].abs();
+            let mut i_max = k;
+            for i in k + 1..N {
+                let value = lu[i][k].abs();
+                if value > max {
+                    max = value;
+                    i_max = i;
+                }
+            }
+            if i_max != k {
+                lu.row_swap_assign(k, i_max);
+                perm.swap(k, i_max);
+                odd = !odd;
+            }
+            // The rest of the column is zero, so there is nothing to eliminate.
+            if max.is_zero() {
+                continue;
+            }
+            for i in k + 1..N {
+                let c = lu[i][k].div(lu[k][k]);
+                lu.0[i][k] = c;
+                for j in k + 1..N {
+                    lu.0[i][j].sub_assign(c.mul(lu[k][j]));
+                }
+            }
+        }
+        Lu { lu, perm, odd }
+    }
+}
+
+impl<S: Field, const N: usize> Lu<N, S> {
+    pub fn l(&self) -> Mat<N, N, S> {
+        Mat::from_fn(|i, j| match Ord::cmp(&i, &j) {
+            Ordering::Less => S::ZERO,
+            Ordering::Equal => S::ONE,
+            Ordering::Greater => self.lu[i][j],
+        })
+    }
+
+    pub fn u(&self) -> Mat<N, N, S> {
+        Mat::from_fn(|i, j| if i > j { S::ZERO } else { self.lu[i][j] })
+    }
+
+    /// The row of the original matrix that each row of `L U` came from.
+    pub fn permutation(&self) -> [usize; N] {
+        self.perm
+    }
+
+    /// Whether some pivot is zero up to roundoff, at most `N ε` times the largest pivot,
+    /// in which case there is no unique solution to solve for.
+    pub fn is_singular(&self) -> bool {
+        let largest = (0..N).fold(S::ZERO, |c, i| c.max(self.lu[i][i].abs()));
+        let tolerance = roundoff(N, largest);
+        (0..N).any(|i| self.lu[i][i].abs() <= tolerance || self.lu[i][i].is_nan())
+    }
+
+    pub fn det(&self) -> S {
+        let det = (0..N).fold(S::ONE, |c, i| c.mul(self.lu[i][i]));
+        if self.odd { det.neg() } else { det }
+    }
+
+    /// The natural log of the magnitude of the determinant, which is negative infinity when `A` is singular.
+    pub fn log_abs_det(&self) -> S {
+        (0..N).fold(S::ZERO, |c, i| c.add(self.lu[i][i].abs().ln()))
+    }
+
+    /// The sign of the determinant, which is zero when `A` is singular.
+    pub fn det_sign(&self) -> S {
+        let sign = (0..N).fold(S::ONE, |c, i| c.mul(self.lu[i][i].sign()));
+        if self.odd { sign.neg() } else { sign }
+    }
+
+    fn substitute(&self, b: Vect<N, S>) -> Vect<N, S> {
+        let mut x = Vect::from_fn(|i| b[self.perm[i]]);
+        for i in 0..N {
+            for j in 0..i {
+                let value = self.lu[i][j].mul(x[j]);
+                x[i].sub_assign(value);
+            }
+        }
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                let value = self.lu[i][j].mul(x[j]);
+                x[i].sub_assign(value);
+            }
+            x[i].div_assign(self.lu[i][i]);
+        }
+        x
+    }
+
+    /// The `x` where `A x = b`, or `None` if `A` is singular.
+    pub fn solve(&self, b: Vect<N, S>) -> Option<Vect<N, S>> {
+        if self.is_singular() {
+            return None;
+        }
+        Some(self.substitute(b))
+    }
+
+    /// The `X` where `A X = B`, solving for each column of `B`.
+    pub fn solve_mat<const M: usize>(&self, b: Mat<N, M, S>) -> Option<Mat<N, M, S>> {
+        if self.is_singular() {
+            return None;
+        }
+        let cols: [_; M] = array::from_fn(|j| self.substitute(b.col(j)));
+        Some(Mat::from_cols(cols))
+    }
+
+    pub fn inverse(&self) -> Option<Mat<N, N, S>> {
+        self.solve_mat(Mat::IDENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_lu() {
+        let a = Mat([
+            [0., 2., -1., 3.],
+            [1., 1., 0.5, -2.],
+            [4., -1., 2., 0.],
+            [2., 0., 1., 1.],
+        ]);
+        let lu = a.lu();
+        assert_close(
+            lu.l() * lu.u(),
+            Mat::from_fn(|i, j| a[lu.permutation()[i]][j]),
+            1e-12,
+        );
+
+        let b = Vect([1., -2., 0.5, 3.]);
+        assert!((a * lu.solve(b).unwrap() - b).magn() < 1e-12);
+        let inv = lu.inverse().unwrap();
+        assert_close(a * inv, Mat::IDENT, 1e-12);
+        assert!(f64::abs(lu.det() * inv.lu().det() - 1.) < 1e-12);
+
+        let singular = Mat([[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]).lu();
+        assert!(singular.is_singular());
+        assert_eq!(singular.det(), 0.);
+        assert!(singular.solve(Vect([1., 2., 3.])).is_none());
+
+        // Roundoff leaves the last pivot of this singular matrix around 1e-16 rather than zero.
+        let roundoff = Mat([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]).lu();
+        assert!(roundoff.is_singular());
+        assert!(roundoff.inverse().is_none());
+        assert!(!Mat([[1e-20, 0.], [0., 3e-20]]).lu().is_singular());
+    }
+}
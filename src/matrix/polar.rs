@@ -0,0 +1,74 @@
+use crate::Rot;
+
+use super::*;
+
+impl<S: Field, const N: usize> Mat<N, N, S> {
+    /// The orthogonal and symmetric positive semidefinite factors whose product is this matrix.
+    ///
+    /// The orthogonal factor is the orthogonal matrix nearest to this one,
+    /// and it is a reflection when the determinant is negative.
+    pub fn polar(self) -> (Mat<N, N, S>, Mat<N, N, S>) {
+        let FullSvd { u, values, v } = self.full_svd();
+        let stretch = Mat::from_fn(|i, j| {
+            (0..N).fold(S::ZERO, |c, k| c.add(v[i][k].mul(values[k]).mul(v[j][k])))
+        });
+        (u * v.transpose(), stretch)
+    }
+}
+
+impl<S: Field> Rot<2, S> {
+    /// The rotation nearest to `mat`, which can be any matrix.
+    pub fn nearest_to_mat(mat: Mat<2, 2, S>) -> Self {
+        let angle = S::atan2(mat[1][0].sub(mat[0][1]), mat[0][0].add(mat[1][1]));
+        Self::angle2(angle)
+    }
+}
+
+impl<S: Field> Rot<3, S> {
+    /// The rotation nearest to `mat`, which can be any matrix.
+    ///
+    /// When the nearest orthogonal matrix is a reflection,
+    /// the direction that `mat` stretches least is flipped back.
+    pub fn nearest_to_mat(mat: Mat<3, 3, S>) -> Self {
+        let FullSvd { mut u, v, .. } = mat.full_svd();
+        if (u * v.transpose()).det() < S::ZERO {
+            for i in 0..3 {
+                u.0[i][2] = u[i][2].neg();
+            }
+        }
+        Self::from_mat(u * v.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::BefAft;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_polar() {
+        let a = Mat([[4., 1., -1.], [0.5, -3., 0.5], [-1., 0.5, 2.]]);
+        let (orthogonal, stretch) = a.polar();
+        assert_close(orthogonal * stretch, a, 1e-12);
+        assert_close(orthogonal.transpose() * orthogonal, Mat::IDENT, 1e-12);
+        assert_close(stretch.transpose(), stretch, 1e-12);
+
+        let rot = Rot::<3, f64>::from_torq(Vect([0.3, -1.2, 0.5]));
+        let drifted = rot.mat() + Mat([[1e-3, 0., -2e-3], [0., 1e-3, 0.], [1e-3, 0., 0.]]);
+        let nearest = Rot::<3, f64>::nearest_to_mat(drifted);
+        assert!(nearest.inv().aft(rot).angle() < 1e-2);
+        assert_close(
+            Rot::<3, f64>::nearest_to_mat(rot.mat()).mat(),
+            rot.mat(),
+            1e-12,
+        );
+
+        let rot = Rot::<2, f64>::angle2(2.5);
+        assert_close(
+            Rot::<2, f64>::nearest_to_mat(rot.mat() * 3.).mat(),
+            rot.mat(),
+            1e-12,
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use super::*;
+
+/// The factorization of a matrix `A`, with at least as many rows as columns, into `A = Q R`,
+/// where `Q` is orthogonal and `R` is upper triangular.
+#[derive(Copy, Clone, Debug)]
+pub struct Qr<const N: usize, const M: usize, S: Field> {
+    pub q: Mat<N, N, S>,
+    pub r: Mat<N, M, S>,
+}
+
+/// The factorization `A P = Q R`, where `P` permutes the columns of `A`
+/// so that the diagonal of `R` never grows in magnitude.
+///
+/// The leading part of the diagonal that is not negligible gives the rank of `A`.
+#[derive(Copy, Clone, Debug)]
+pub struct PivotedQr<const N: usize, const M: usize, S: Field> {
+    pub q: Mat<N, N, S>,
+    pub r: Mat<N, M, S>,
+    /// The column of `A` that each column of `Q R` came from.
+    pub perm: [usize; M],
+}
+
+impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+    /// Householder reflections zeroing each column of `R` below the diagonal in turn,
+    /// first swapping in the remaining column of largest norm if `pivot` is set.
+    fn householder(self, pivot: bool) -> (Mat<N, N, S>, Mat<N, M, S>, [usize; M]) {
+        const { assert!(N >= M) }
+        let mut q = Mat::<N, N, S>::IDENT;
+        let mut r = self;
+        let mut perm = array::from_fn(|j| j);
+        for k in 0..M {
+            let rest = |r: &Self, j: usize| (k..N).fold(S::ZERO, |c, i| c.add(r[i][j].pow(2)));
+            if pivot {
+                let mut max = rest(&r, k);
+                let mut j_max = k;
+                for j in k + 1..M {
+                    let value = rest(&r, j);
+                    if value > max {
+                        max = value;
+                        j_max = j;
+                    }
+                }
+                if j_max != k {
+                    for i in 0..N {
+                        r.0[i].swap(k, j_max);
+                    }
+                    perm.swap(k, j_max);
+                }
+            }
+
+            // The reflection taking the rest of column `k` to `alpha` times the `k`th axis,
+            // choosing the sign of `alpha` to avoid cancellation.
+            let magn = rest(&r, k).sqrt();
+            let alpha = if r[k][k] < S::ZERO { magn } else { magn.neg() };
+            let mut v = Vect::<N, S>::ZERO;
+            for i in k..N {
+                v[i] = r[i][k];
+            }
+            v[k].sub_assign(alpha);
+            let sqr_magn = v.sqr_magn();
+            if sqr_magn.is_zero() {
+                continue;
+            }
+            let scale = S::TWO.div(sqr_magn);
+
+            for j in k..M {
+                let dot = (k..N).fold(S::ZERO, |c, i| c.add(v[i].mul(r[i][j])));
+                for i in k..N {
+                    r.0[i][j].sub_assign(v[i].mul(dot.mul(scale)));
+                }
+            }
+            for i in 0..N {
+                let dot = (k..N).fold(S::ZERO, |c, j| c.add(q[i][j].mul(v[j])));
+                for j in k..N {
+                    q.0[i][j].sub_assign(dot.mul(scale).mul(v[j]));
+                }
+            }
+            for i in k + 1..N {
+                r.0[i][k] = S::ZERO;
+            }
+        }
+        (q, r, perm)
+    }
+
+    pub fn qr(self) -> Qr<N, M, S> {
+        let (q, r, _) = self.householder(false);
+        Qr { q, r }
+    }
+
+    pub fn qr_pivoted(self) -> PivotedQr<N, M, S> {
+        let (q, r, perm) = self.householder(true);
+        PivotedQr { q, r, perm }
+    }
+
+    /// The `x` minimizing the magnitude of `self * x - b`.
+    ///
+    /// Columns count as dependent when their diagonal entry of `R` is within roundoff of zero,
+    /// at most `N ε` times the largest.
+    pub fn least_squares(self, b: Vect<N, S>) -> Vect<M, S> {
+        let qr = self.qr_pivoted();
+        let largest = (0..M).fold(S::ZERO, |c, i| c.max(qr.r[i][i].abs()));
+        qr.least_squares(b, qr.rank(roundoff(N, largest)))
+    }
+}
+
+/// Solves the leading `rank` rows of `R z = Qᵀ b`, with the rest of `z` zero.
+fn back_substitute<S: Field, const N: usize, const M: usize>(
+    q: Mat<N, N, S>,
+    r: Mat<N, M, S>,
+    b: Vect<N, S>,
+    rank: usize,
+) -> Vect<M, S> {
+    let mut z = Vect::from_fn(|i| if i < rank { q.col(i).dot(b) } else { S::ZERO });
+    for i in (0..rank).rev() {
+        for j in i + 1..rank {
+            let value = r[i][j].mul(z[j]);
+            z[i].sub_assign(value);
+        }
+        z[i].div_assign(r[i][i]);
+    }
+    z
+}
+
+impl<S: Field, const N: usize, const M: usize> Qr<N, M, S> {
+    /// The `x` minimizing the magnitude of `A x - b`, or `None` if the columns of `A` are dependent.
+    pub fn least_squares(&self, b: Vect<N, S>) -> Option<Vect<M, S>> {
+        if (0..M).any(|i| self.r[i][i].is_zero()) {
+            return None;
+        }
+        Some(back_substitute(self.q, self.r, b, M))
+    }
+}
+
+impl<S: Field, const N: usize, const M: usize> PivotedQr<N, M, S> {
+    /// How many diagonal entries of `R` are larger in magnitude than `tolerance`.
+    pub fn rank(&self, tolerance: S) -> usize {
+        (0..M)
+            .take_while(|&i| self.r[i][i].abs() > tolerance)
+            .count()
+    }
+
+    /// A least squares solution using only the first `rank` columns of `Q R`,
+    /// which is the basic solution when `rank` is the rank of `A`.
+    pub fn least_squares(&self, b: Vect<N, S>, rank: usize) -> Vect<M, S> {
+        let z = back_substitute(self.q, self.r, b, rank);
+        let mut x = Vect::ZERO;
+        for j in 0..M {
+            x[self.perm[j]] = z[j];
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_qr() {
+        let a = Mat([
+            [1., 2., 0.],
+            [0., 1., -1.],
+            [2., 0., 1.],
+            [1., 1., 1.],
+            [-1., 3., 0.5],
+        ]);
+        let qr = a.qr();
+        assert_close(qr.q * qr.r, a, 1e-12);
+        assert_close(
+            Mat::<5, 5, f64>::from_fn(|i, j| qr.q.col(i).dot(qr.q.col(j))),
+            Mat::IDENT,
+            1e-12,
+        );
+
+        // The residual of a least squares solution is orthogonal to the columns.
+        let b = Vect([1., -2., 0.5, 3., 1.]);
+        let x = qr.least_squares(b).unwrap();
+        let residual = a * x - b;
+        assert!((0..3).all(|j| f64::abs(a.col(j).dot(residual)) < 1e-12));
+        assert!((a.least_squares(b) - x).magn() < 1e-12);
+
+        let dependent =
+            Mat::<5, 3, f64>::from_fn(|i, j| if j == 2 { a[i][0] + a[i][1] } else { a[i][j] });
+        let qr = dependent.qr_pivoted();
+        assert_eq!(qr.rank(1e-12), 2);
+        let residual = dependent * qr.least_squares(b, 2) - b;
+        assert!((0..3).all(|j| f64::abs(dependent.col(j).dot(residual)) < 1e-12));
+
+        // Roundoff leaves the last diagonal entry of R around 1e-17 rather than zero.
+        let dependent =
+            Mat::<5, 3, f64>::from_fn(|i, j| [a[i][0], a[i][1], 0.1 * a[i][0] + 0.3 * a[i][1]][j]);
+        let qr = dependent.qr_pivoted();
+        assert_close(dependent.least_squares(b), qr.least_squares(b, 2), 1e-12);
+    }
+}
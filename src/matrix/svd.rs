@@ -0,0 +1,196 @@
+use super::*;
+
+/// The factorization of a matrix `A` into `U diag(values) Vᵀ`,
+/// where the columns of `U` and `V` are orthonormal and the singular values are nonnegative and descending.
+///
+/// There is a value for each column of `A`. When `A` has fewer rows than columns,
+/// the values past the number of rows are zero, and so are their columns of `U`.
+#[derive(Copy, Clone, Debug)]
+pub struct Svd<const N: usize, const M: usize, S: Field> {
+    pub u: Mat<N, M, S>,
+    pub values: Vect<M, S>,
+    pub v: Mat<M, M, S>,
+}
+
+/// An [`Svd`] where `U` is square and orthogonal,
+/// so that `A = U Σ Vᵀ` where `Σ` has the values down its diagonal.
+#[derive(Copy, Clone, Debug)]
+pub struct FullSvd<const N: usize, const M: usize, S: Field> {
+    pub u: Mat<N, N, S>,
+    pub values: Vect<M, S>,
+    pub v: Mat<M, M, S>,
+}
+
+impl<S: Field, const N: usize, const M: usize> Mat<N, M, S> {
+    /// By one-sided Jacobi rotations, which orthogonalize the columns of `A V`.
+    pub fn svd(self) -> Svd<N, M, S> {
+        let mut a = self;
+        let mut v = Mat::<M, M, S>::IDENT;
+        let total = (0..M).fold(S::ZERO, |c, j| c.add(a.col(j).sqr_magn()));
+        for _ in 0..64 {
+            let mut rotated = false;
+            for p in 0..M {
+                for q in p + 1..M {
+                    let (alpha, beta) = (a.col(p).sqr_magn(), a.col(q).sqr_magn());
+                    let gamma = a.col(p).dot(a.col(q));
+                    let norm = alpha.mul(beta).sqrt();
+                    // The columns are orthogonal enough, relative to themselves or to the whole matrix.
+                    if norm.add(gamma.abs()) == norm || total.add(gamma.abs()) == total {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = beta.sub(alpha).div(gamma.mul(S::TWO));
+                    let t = S::ONE.div(zeta.abs().add(zeta.pow(2).add(S::ONE).sqrt()));
+                    let t = if zeta < S::ZERO { t.neg() } else { t };
+                    let cos = S::ONE.div(t.pow(2).add(S::ONE).sqrt());
+                    let sin = t.mul(cos);
+                    for k in 0..N {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a.0[k][p] = cos.mul(akp).sub(sin.mul(akq));
+                        a.0[k][q] = sin.mul(akp).add(cos.mul(akq));
+                    }
+                    for k in 0..M {
+                        let (vkp, vkq) = (v[k][p], v[k][q]);
+                        v.0[k][p] = cos.mul(vkp).sub(sin.mul(vkq));
+                        v.0[k][q] = sin.mul(vkp).add(cos.mul(vkq));
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let magns = Vect::<M, S>::from_fn(|j| a.col(j).magn());
+        let mut order: [usize; M] = array::from_fn(|j| j);
+        order.sort_by(|&i, &j| magns[j].cmp(&magns[i]));
+        Svd {
+            u: Mat::from_fn(|i, j| {
+                let magn = magns[order[j]];
+                if magn.is_zero() {
+                    S::ZERO
+                } else {
+                    a[i][order[j]].div(magn)
+                }
+            }),
+            values: Vect::from_fn(|j| magns[order[j]]),
+            v: Mat::from_fn(|i, j| v[i][order[j]]),
+        }
+    }
+
+    /// The columns of `U` past those with nonzero singular values are completed to an orthonormal basis.
+    pub fn full_svd(self) -> FullSvd<N, M, S> {
+        let Svd { u, values, v } = self.svd();
+        let len = (0..Ord::min(M, N))
+            .take_while(|&j| !values[j].is_zero())
+            .count();
+        let qr = Mat::<N, N, S>::from_fn(|i, j| if j < len { u[i][j] } else { S::ZERO }).qr();
+        FullSvd {
+            // The reflections only flip the signs of the columns they are given.
+            u: Mat::from_fn(|i, j| {
+                if j < len && qr.r[j][j] < S::ZERO {
+                    qr.q[i][j].neg()
+                } else {
+                    qr.q[i][j]
+                }
+            }),
+            values,
+            v,
+        }
+    }
+
+    /// The least squares solution operator, treating singular values no larger than `tolerance` as zero.
+    pub fn pseudo_inverse(self, tolerance: S) -> Mat<M, N, S> {
+        self.svd().pseudo_inverse(tolerance)
+    }
+
+    /// How many singular values are larger than `tolerance`.
+    pub fn rank(self, tolerance: S) -> usize {
+        self.svd().rank(tolerance)
+    }
+
+    /// The ratio of the largest to the smallest singular value, which is infinite when the matrix is rank deficient.
+    pub fn condition_number(self) -> S {
+        self.svd().condition_number()
+    }
+
+    /// An orthonormal basis of the vectors that the matrix takes to zero,
+    /// treating singular values no larger than `tolerance` as zero.
+    pub fn null_space(self, tolerance: S) -> impl Iterator<Item = Vect<M, S>> {
+        self.svd().null_space(tolerance)
+    }
+}
+
+impl<S: Field, const N: usize, const M: usize> Svd<N, M, S> {
+    pub fn pseudo_inverse(&self, tolerance: S) -> Mat<M, N, S> {
+        let inv = Vect::<M, S>::from_fn(|k| {
+            if self.values[k] > tolerance {
+                S::ONE.div(self.values[k])
+            } else {
+                S::ZERO
+            }
+        });
+        Mat::from_fn(|i, j| {
+            (0..M).fold(S::ZERO, |c, k| {
+                c.add(self.v[i][k].mul(inv[k]).mul(self.u[j][k]))
+            })
+        })
+    }
+
+    pub fn rank(&self, tolerance: S) -> usize {
+        (0..M).take_while(|&k| self.values[k] > tolerance).count()
+    }
+
+    pub fn condition_number(&self) -> S {
+        let len = Ord::min(M, N);
+        if len == 0 {
+            return S::ONE;
+        }
+        let least = self.values[len - 1];
+        if least.is_zero() {
+            S::INFINITY
+        } else {
+            self.values[0].div(least)
+        }
+    }
+
+    pub fn null_space(self, tolerance: S) -> impl Iterator<Item = Vect<M, S>> {
+        (self.rank(tolerance)..M).map(move |k| self.v.col(k))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_svd() {
+        let a = Mat([[1., 2., 0.], [0., 1., -1.], [2., 0., 1.], [1., 1., 1.]]);
+        let svd = a.svd();
+        let sigma = Mat::<3, 3, f64>::from_fn(|i, j| if i == j { svd.values[i] } else { 0. });
+        assert_close(svd.u * sigma * svd.v.transpose(), a, 1e-12);
+        assert_close(svd.u.transpose() * svd.u, Mat::IDENT, 1e-12);
+        assert!(svd.values[0] >= svd.values[1] && svd.values[1] >= svd.values[2]);
+
+        let full = a.full_svd();
+        assert_close(full.u.transpose() * full.u, Mat::IDENT, 1e-12);
+        let sigma = Mat::<4, 3, f64>::from_fn(|i, j| if i == j { full.values[j] } else { 0. });
+        assert_close(full.u * sigma * full.v.transpose(), a, 1e-12);
+
+        let pinv = a.pseudo_inverse(1e-12);
+        assert_close(pinv * a, Mat::IDENT, 1e-12);
+
+        // Wide and rank deficient, with the third row the sum of the first two.
+        let b = Mat([[1., 2., 0., 1.], [0., 1., -1., 2.], [1., 3., -1., 3.]]);
+        assert_eq!(b.rank(1e-9), 2);
+        assert!(b.condition_number() > 1e9);
+        let null: Vec<_> = b.null_space(1e-9).collect();
+        assert_eq!(null.len(), 2);
+        for n in null {
+            assert!((b * n).magn() < 1e-12);
+        }
+        let pinv = b.pseudo_inverse(1e-9);
+        assert_close(b * pinv * b, b, 1e-12);
+    }
+}
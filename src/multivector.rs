@@ -0,0 +1,424 @@
+use std::{array, ops::*};
+
+use crate::{
+    Vect,
+    ops::Wedge,
+    traits::{Field, Ring},
+};
+
+/// The components of a multivector, one per basis blade.
+pub trait Blades<S: Ring>: Copy + PartialEq + AsRef<[S]> + AsMut<[S]> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_fn(f: impl FnMut(usize) -> S) -> Self;
+}
+
+impl<S: Ring, const K: usize> Blades<S> for [S; K] {
+    const ZERO: Self = [S::ZERO; K];
+    const ONE: Self = {
+        let mut array = [S::ZERO; K];
+        array[0] = S::ONE;
+        array
+    };
+
+    fn from_fn(f: impl FnMut(usize) -> S) -> Self {
+        array::from_fn(f)
+    }
+}
+
+/// The dimensions that have multivectors, which are 0 through 8.
+///
+/// Each is implemented separately, since stable Rust can't compute the `1 << N` blade count from a const generic.
+/// Past 8 dimensions a multivector has more than 256 components, and its products grow with the square of that.
+pub trait CliffordDim<const N: usize> {
+    type Blades<S: Ring>: Blades<S>;
+}
+
+macro_rules! clifford_dims {
+    ($($n:literal),*) => {$(
+        impl CliffordDim<$n> for () {
+            type Blades<S: Ring> = [S; 1 << $n];
+        }
+    )*}
+}
+
+clifford_dims!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+
+/// An element of the geometric algebra over `N` euclidean dimensions.
+///
+/// Components are indexed by basis blade, where bit `i` of the index is set when
+/// the blade contains the `i`th axis.  So `mv[0]` is the scalar part, `mv[0b101]` is the `xz` part,
+/// and `mv[(1 << N) - 1]` is the pseudoscalar part.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Multivector<const N: usize, S: Ring>(<() as CliffordDim<N>>::Blades<S>)
+where
+    (): CliffordDim<N>;
+
+/// Whether reordering the product of blades `a` and `b` into canonical order flips the sign.
+const fn reorder_sign(a: usize, b: usize) -> bool {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    swaps % 2 == 1
+}
+
+/// The blades of grade 2, ordered `xy, xz, .., yz, ..`.
+fn bivector_blades<const N: usize>() -> impl Iterator<Item = usize> {
+    (0..N).flat_map(|i| (i + 1..N).map(move |j| (1 << i) | (1 << j)))
+}
+
+impl<const N: usize, S: Ring> Default for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize, S: Ring> Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    pub const ZERO: Self = Self(Blades::ZERO);
+    pub const ONE: Self = Self(Blades::ONE);
+
+    pub fn from_fn<F: Fn(usize) -> S>(f: F) -> Self {
+        Self(Blades::from_fn(f))
+    }
+
+    pub fn scalar(s: S) -> Self {
+        let mut mv = Self::ZERO;
+        mv[0] = s;
+        mv
+    }
+
+    pub fn blades(&self) -> &[S] {
+        self.0.as_ref()
+    }
+
+    pub fn map<T: Ring>(self, f: impl Fn(S) -> T) -> Multivector<N, T> {
+        Multivector::from_fn(|i| f(self[i]))
+    }
+
+    pub fn from_bivector<const B: usize>(bi: Vect<B, S>) -> Self {
+        const { assert!(B == N * (N.saturating_sub(1)) / 2) }
+        let mut mv = Self::ZERO;
+        for (blade, value) in bivector_blades::<N>().zip(bi.0) {
+            mv[blade] = value;
+        }
+        mv
+    }
+
+    pub fn vect(self) -> Vect<N, S> {
+        Vect::from_fn(|i| self[1 << i])
+    }
+
+    pub fn bivector<const B: usize>(self) -> Vect<B, S> {
+        const { assert!(B == N * (N.saturating_sub(1)) / 2) }
+        let mut bi = Vect::ZERO;
+        for (i, blade) in bivector_blades::<N>().enumerate() {
+            bi[i] = self[blade];
+        }
+        bi
+    }
+
+    /// The part of grade `k`.
+    pub fn grade(self, k: usize) -> Self {
+        Self::from_fn(|i| {
+            if i.count_ones() as usize == k {
+                self[i]
+            } else {
+                S::ZERO
+            }
+        })
+    }
+
+    /// The parts of even grade.
+    pub fn even(self) -> Self {
+        Self::from_fn(|i| {
+            if i.count_ones() % 2 == 0 {
+                self[i]
+            } else {
+                S::ZERO
+            }
+        })
+    }
+
+    /// Reverses the order of the vectors in every blade.
+    pub fn rev(self) -> Self {
+        Self::from_fn(|i| {
+            if i.count_ones() % 4 >= 2 {
+                self[i].neg()
+            } else {
+                self[i]
+            }
+        })
+    }
+
+    /// The Hodge dual, taking each blade to the one spanning the remaining axes.
+    pub fn dual(self) -> Self {
+        let full = (1 << N) - 1;
+        Self::from_fn(|i| {
+            let value = self[full ^ i];
+            if reorder_sign(full ^ i, i) {
+                value.neg()
+            } else {
+                value
+            }
+        })
+    }
+
+    /// The left contraction, which lowers the grade of `other` by the grade of `self`.
+    pub fn inner(self, other: Self) -> Self {
+        self.product(other, |a, b| a & !b == 0)
+    }
+
+    pub fn sqr_magn(self) -> S {
+        self.blades().iter().fold(S::ZERO, |c, &n| c.add(n.mul(n)))
+    }
+
+    fn product(self, other: Self, keep: impl Fn(usize, usize) -> bool) -> Self {
+        let mut out = Self::ZERO;
+        for (a, &x) in self.blades().iter().enumerate() {
+            if x.is_zero() {
+                continue;
+            }
+            for (b, &y) in other.blades().iter().enumerate() {
+                if y.is_zero() || !keep(a, b) {
+                    continue;
+                }
+                if reorder_sign(a, b) {
+                    out[a ^ b].sub_assign(x.mul(y));
+                } else {
+                    out[a ^ b].add_assign(x.mul(y));
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<const N: usize, S: Field> Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    pub fn magn(self) -> S {
+        self.sqr_magn().sqrt()
+    }
+
+    /// The exponential, by scaling and squaring a truncated power series.
+    ///
+    /// For a bivector this is a rotor.
+    pub fn exp(self) -> Self {
+        let mut scaled = self;
+        let mut squarings = 0;
+        while scaled.magn() > S::HALF && squarings < 64 {
+            scaled = scaled * S::HALF;
+            squarings += 1;
+        }
+
+        let mut n = S::ZERO;
+        let mut term = Self::ONE;
+        let mut sum = Self::ONE;
+        for _ in 0..16 {
+            n.add_assign(S::ONE);
+            term = term * scaled * S::ONE.div(n);
+            sum += term;
+        }
+
+        for _ in 0..squarings {
+            sum = sum * sum;
+        }
+        sum
+    }
+}
+
+impl<const N: usize> Multivector<N, f32>
+where
+    (): CliffordDim<N>,
+{
+    pub fn to_f64(self) -> Multivector<N, f64> {
+        self.map(|x| x as _)
+    }
+}
+
+impl<const N: usize> Multivector<N, f64>
+where
+    (): CliffordDim<N>,
+{
+    pub fn to_f32(self) -> Multivector<N, f32> {
+        self.map(|x| x as _)
+    }
+}
+
+impl<const N: usize, S: Ring> From<Vect<N, S>> for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn from(value: Vect<N, S>) -> Self {
+        let mut mv = Self::ZERO;
+        for i in 0..N {
+            mv[1 << i] = value[i];
+        }
+        mv
+    }
+}
+
+impl<const N: usize, S: Ring> Index<usize> for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = S;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.0.as_ref()[i]
+    }
+}
+
+impl<const N: usize, S: Ring> IndexMut<usize> for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.0.as_mut()[i]
+    }
+}
+
+impl<const N: usize, S: Ring> Add for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|i| self[i].add(rhs[i]))
+    }
+}
+
+impl<const N: usize, S: Ring> Sub for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|i| self[i].sub(rhs[i]))
+    }
+}
+
+impl<const N: usize, S: Ring> Neg for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_fn(|i| self[i].neg())
+    }
+}
+
+impl<const N: usize, S: Ring> AddAssign for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        for (value, &other) in self.0.as_mut().iter_mut().zip(rhs.blades()) {
+            value.add_assign(other)
+        }
+    }
+}
+
+impl<const N: usize, S: Ring> SubAssign for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for (value, &other) in self.0.as_mut().iter_mut().zip(rhs.blades()) {
+            value.sub_assign(other)
+        }
+    }
+}
+
+impl<const N: usize, S: Ring> Mul<S> for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Self::from_fn(|i| self[i].mul(rhs))
+    }
+}
+
+/// The geometric product.
+impl<const N: usize, S: Ring> Mul for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.product(rhs, |_, _| true)
+    }
+}
+
+impl<const N: usize, S: Ring> Wedge for Multivector<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Self;
+
+    fn wedge(self, other: Self) -> Self {
+        self.product(other, |a, b| a & b == 0)
+    }
+}
+
+impl<const N: usize, S: Ring> Wedge for Vect<N, S>
+where
+    (): CliffordDim<N>,
+{
+    type Output = Multivector<N, S>;
+
+    fn wedge(self, other: Self) -> Multivector<N, S> {
+        let mut mv = Multivector::ZERO;
+        for i in 0..N {
+            for j in i + 1..N {
+                mv[(1 << i) | (1 << j)] = self[i].mul(other[j]).sub(self[j].mul(other[i]));
+            }
+        }
+        mv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_products() {
+        let x = Multivector::<3, f64>::from(Vect([1., 0., 0.]));
+        let y = Multivector::from(Vect([0., 1., 0.]));
+        let z = Multivector::from(Vect([0., 0., 1.]));
+
+        assert_eq!(x * y, x.wedge(y));
+        assert_eq!(y * x, -(x * y));
+        assert_eq!(x * x, Multivector::ONE);
+        assert_eq!((x * y).dual(), z);
+        assert_eq!(x.inner(x * y), y);
+        assert_eq!((x * y * z).rev(), -(x * y * z));
+    }
+
+    #[test]
+    fn test_exp() {
+        let angle = 1.3;
+        let bi = Multivector::<3, f64>::from_bivector(Vect([angle, 0., 0.]));
+        let rot = bi.exp();
+        assert!((rot[0] - angle.cos()).abs() < 1e-12);
+        assert!((rot[0b11] - angle.sin()).abs() < 1e-12);
+    }
+}
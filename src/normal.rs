@@ -36,6 +36,25 @@ impl<S: Field, const N: usize> Nrml<N, S> {
         v[i] = 1S;
         Self(v)
     }
+
+    /// Interpolates along the great circle from `self` to `other` at a constant rate.
+    ///
+    /// When `other` is opposite `self`, some perpendicular great circle is picked.
+    pub fn slerp(self, other: Self, t: S) -> Self {
+        let (sin, perp) = match other.rej(self).magn_normal() {
+            Some(magn_normal) => magn_normal,
+            None if self.dot(other) > S::ZERO || N == 1 => {
+                return if t < S::HALF { self } else { other };
+            }
+            None => {
+                let i = if self[0].abs() < S::HALF { 0 } else { 1 };
+                (S::ZERO, Nrml::axis(i).rej(self).normal().unwrap())
+            }
+        };
+        let angle = S::atan2(sin, self.dot(other)).mul(t);
+        let (sin, cos) = angle.sin_cos();
+        (self * cos + perp * sin).normal().unwrap_or(self)
+    }
 }
 
 impl<const N: usize> Nrml<N, f32> {
@@ -10,6 +10,12 @@ pub trait Cross<T = Self> {
     fn cross(self, other: T) -> Self::Output;
 }
 
+pub trait Wedge<T = Self> {
+    type Output;
+
+    fn wedge(self, other: T) -> Self::Output;
+}
+
 pub trait Apl<T> {
     type Output;
 
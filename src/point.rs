@@ -0,0 +1,140 @@
+use std::ops::*;
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Vect,
+    traits::{Field, Ring},
+};
+
+/// A position, as opposed to a [`Vect`], which is a displacement.
+///
+/// The difference of two points is a vector, and a point moved by a vector is a point.
+/// Transformations translate points, but not vectors.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        bound(
+            serialize = "[S; N]:  Serialize",
+            deserialize = "[S; N]: Deserialize<'de>",
+        ),
+        transparent,
+    )
+)]
+pub struct Pnt<const N: usize, S>(pub [S; N]);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, S: Copy> Zeroable for Pnt<N, S> where [S; N]: Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, S: Copy> Pod for Pnt<N, S> where [S; N]: Pod {}
+
+impl<const N: usize, S: Ring> Default for Pnt<N, S> {
+    fn default() -> Self {
+        Self::ORIGIN
+    }
+}
+
+impl<S: Ring, const N: usize> Pnt<N, S> {
+    pub const ORIGIN: Self = Pnt([S::ZERO; N]);
+
+    /// The displacement of this point from the origin.
+    pub fn vect(self) -> Vect<N, S> {
+        Vect(self.0)
+    }
+
+    /// The weighted combination of `terms`, whose weights should sum to one.
+    ///
+    /// This is taken relative to the first point, so it doesn't depend on where the origin is.
+    pub fn affine_combination<const L: usize>(terms: [(Self, S); L]) -> Self {
+        let Some(&(first, _)) = terms.first() else {
+            return Self::ORIGIN;
+        };
+        first
+            + terms.into_iter().fold(Vect::ZERO, |c, (point, weight)| {
+                c + (point - first) * weight
+            })
+    }
+
+    pub fn lerp(self, other: Self, t: S) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<S: Field, const N: usize> Pnt<N, S> {
+    pub fn distance(self, other: Self) -> S {
+        (other - self).magn()
+    }
+}
+
+impl<S: Ring, const N: usize> Index<usize> for Pnt<N, S> {
+    type Output = S;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.0[i]
+    }
+}
+
+impl<S: Ring, const N: usize> IndexMut<usize> for Pnt<N, S> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.0[i]
+    }
+}
+
+impl<S: Ring, const N: usize> Sub for Pnt<N, S> {
+    type Output = Vect<N, S>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.vect() - rhs.vect()
+    }
+}
+
+impl<S: Ring, const N: usize> Add<Vect<N, S>> for Pnt<N, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Vect<N, S>) -> Self::Output {
+        Pnt((self.vect() + rhs).0)
+    }
+}
+
+impl<S: Ring, const N: usize> Sub<Vect<N, S>> for Pnt<N, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vect<N, S>) -> Self::Output {
+        Pnt((self.vect() - rhs).0)
+    }
+}
+
+impl<S: Ring, const N: usize> AddAssign<Vect<N, S>> for Pnt<N, S> {
+    fn add_assign(&mut self, rhs: Vect<N, S>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<S: Ring, const N: usize> SubAssign<Vect<N, S>> for Pnt<N, S> {
+    fn sub_assign(&mut self, rhs: Vect<N, S>) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rig, Rot, ops::Apl};
+
+    #[test]
+    fn test_pnt() {
+        let rig = Rig::<3, f64>::new(Vect([1., -2., 0.5]), Rot::from_torq(Vect([0.3, -1.2, 0.5])));
+        let (a, b) = (Pnt([0.4, -2., 1.3]), Pnt([-1., 0.5, 2.]));
+        assert!((rig.apl(b) - rig.apl(a) - rig.apl(b - a)).magn() < 1e-9);
+        assert!((rig.apl(a) - Pnt::ORIGIN - rig.apl(a.vect()) - rig.trans).magn() < 1e-9);
+
+        let mid = Pnt::affine_combination([(a, 0.25), (b, 0.25), (a, 0.5)]);
+        assert!(mid.distance(a.lerp(b, 0.25)) < 1e-12);
+    }
+}
@@ -1,4 +1,4 @@
 pub use crate::{
-    Mat, Nrml, Rig, Rot, Vect,
-    ops::{Apl, BefAft, Cross, Dot, ProjRej},
+    Aff, DualRot, Frustum, Mat, Multivector, Nrml, Pnt, Rig, Rot, Sim, Vect,
+    ops::{Apl, BefAft, Cross, Dot, ProjRej, Wedge},
 };
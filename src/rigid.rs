@@ -2,12 +2,16 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    Mat, Vect,
-    ops::{Apl, BefAft},
+    Mat, Nrml, Pnt, Vect,
+    ops::{Apl, BefAft, Cross, Dot},
     rotor::*,
     traits::*,
 };
 
+mod lie;
+
+pub use lie::Screw;
+
 #[derive(Copy, Clone, Default)]
 #[cfg_attr(
     feature = "serde",
@@ -73,6 +77,9 @@ where
     }
 }
 
+/// Only rotates, leaving out the translation; apply the motion to a [`Pnt`] to move a position.
+///
+/// Before 0.6 this translated too, as there was no point type to tell positions apart.
 impl<const N: usize, S: Field> Apl<Vect<N, S>> for Rig<N, S>
 where
     (): RotDim<N>,
@@ -80,6 +87,17 @@ where
     type Output = Vect<N, S>;
 
     fn apl(self, other: Vect<N, S>) -> Vect<N, S> {
+        self.rot.apl(other)
+    }
+}
+
+impl<const N: usize, S: Field> Apl<Pnt<N, S>> for Rig<N, S>
+where
+    (): RotDim<N>,
+{
+    type Output = Pnt<N, S>;
+
+    fn apl(self, other: Pnt<N, S>) -> Pnt<N, S> {
         self.rot.apl(other) + self.trans
     }
 }
@@ -97,6 +115,19 @@ where
 }
 
 impl<S: Field> Rig<3, S> {
+    /// A camera at `eye` looking at `target`, oriented by [`Rot::look_rotation`].
+    ///
+    /// This places the camera in the world; its inverse is the view transform.
+    pub fn look_at(eye: Pnt<3, S>, target: Pnt<3, S>, up: Nrml<3, S>) -> Self {
+        Self {
+            trans: eye.vect(),
+            rot: match (target - eye).normal() {
+                Some(forward) => Rot::look_rotation(forward, up),
+                None => Rot::IDENT,
+            },
+        }
+    }
+
     pub fn to_hmat(self) -> Mat<4, 4, S> {
         Mat::affine(self.rot.mat(), self.trans)
     }
@@ -0,0 +1,173 @@
+use super::*;
+
+use crate::Nrml;
+
+/// Twists are `[vx, vy, w]`, the velocity in the moving frame and then the angular velocity.
+impl<S: Field> Rig<2, S> {
+    /// The motion reached by following `twist` for unit time.
+    pub fn exp(twist: Vect<3, S>) -> Self {
+        let Vect([x, y, angle]) = twist;
+        let (b, c, _) = coefficients(angle.abs());
+        let sinc = S::ONE.sub(angle.pow(2).mul(c));
+        let cosc = b.mul(angle);
+        Self {
+            trans: Vect([sinc.mul(x).sub(cosc.mul(y)), cosc.mul(x).add(sinc.mul(y))]),
+            rot: Rot::angle2(angle),
+        }
+    }
+
+    /// The twist that [`Self::exp`] takes to this motion, turning the shorter way around.
+    pub fn log(self) -> Vect<3, S> {
+        let angle = self.rot.signed_angle();
+        let (b, c, _) = coefficients(angle.abs());
+        let sinc = S::ONE.sub(angle.pow(2).mul(c));
+        let cosc = b.mul(angle);
+        let det = sinc.pow(2).add(cosc.pow(2));
+        let Vect([x, y]) = self.trans;
+        Vect([
+            sinc.mul(x).add(cosc.mul(y)).div(det),
+            sinc.mul(y).sub(cosc.mul(x)).div(det),
+            angle,
+        ])
+    }
+
+    /// Takes twists on the right of this motion to ones on the left,
+    /// so that `self.aft(Rig::exp(twist))` is `Rig::exp(self.adjoint() * twist).aft(self)`.
+    pub fn adjoint(self) -> Mat<3, 3, S> {
+        let [[xx, xy], [yx, yy]] = self.rot.mat().0;
+        let Vect([x, y]) = self.trans;
+        Mat([[xx, xy, y], [yx, yy, x.neg()], [S::ZERO, S::ZERO, S::ONE]])
+    }
+
+    /// Interpolates from `self` to `other` along the screw motion between them, at a constant rate.
+    pub fn sclerp(self, other: Self, t: S) -> Self {
+        self.aft(Self::exp(self.inv().aft(other).log() * t))
+    }
+}
+
+/// Twists are `[vx, vy, vz, wx, wy, wz]`, the velocity in the moving frame and then the angular velocity.
+impl<S: Field> Rig<3, S> {
+    /// The motion reached by following `twist` for unit time.
+    pub fn exp(twist: Vect<6, S>) -> Self {
+        let Vect([x, y, z, wx, wy, wz]) = twist;
+        let torq = Vect([wx, wy, wz]);
+        Self {
+            trans: Rot::<3, S>::left_jacobian(torq) * Vect([x, y, z]),
+            rot: Rot::from_torq(torq),
+        }
+    }
+
+    /// The twist that [`Self::exp`] takes to this motion, turning the shorter way around.
+    pub fn log(self) -> Vect<6, S> {
+        let torq = self.rot.box_minus(Rot::IDENT);
+        let Vect([x, y, z]) = Rot::<3, S>::left_jacobian_inv(torq) * self.trans;
+        let Vect([wx, wy, wz]) = torq;
+        Vect([x, y, z, wx, wy, wz])
+    }
+
+    /// Takes twists on the right of this motion to ones on the left,
+    /// so that `self.aft(Rig::exp(twist))` is `Rig::exp(self.adjoint() * twist).aft(self)`.
+    pub fn adjoint(self) -> Mat<6, 6, S> {
+        let rot = self.rot.mat();
+        let skew = Rot::<3, S>::hat(self.trans) * rot;
+        Mat::from_fn(|i, j| match (i < 3, j < 3) {
+            (true, true) => rot[i][j],
+            (true, false) => skew[i][j - 3],
+            (false, true) => S::ZERO,
+            (false, false) => rot[i - 3][j - 3],
+        })
+    }
+
+    /// Interpolates from `self` to `other` along the screw motion between them, at a constant rate.
+    pub fn sclerp(self, other: Self, t: S) -> Self {
+        self.aft(Self::exp(self.inv().aft(other).log() * t))
+    }
+
+    /// The screw motion that this is, by Chasles' theorem.
+    /// `None` for the identity, which has no axis.
+    ///
+    /// A pure translation is a screw with an angle of zero.
+    pub fn screw(self) -> Option<Screw<S>> {
+        let torq = self.rot.box_minus(Rot::IDENT);
+        if let Some((angle, axis)) = torq.magn_normal() {
+            let distance = self.trans.dot(axis);
+            let perp = self.trans - axis * distance;
+            let cot = S::ONE.div(angle.mul(S::HALF).tan());
+            Some(Screw {
+                point: Pnt::ORIGIN + (perp + axis.cross(perp) * cot) * S::HALF,
+                axis,
+                angle,
+                distance,
+            })
+        } else {
+            let (distance, axis) = self.trans.magn_normal()?;
+            Some(Screw {
+                point: Pnt::ORIGIN,
+                axis,
+                angle: S::ZERO,
+                distance,
+            })
+        }
+    }
+}
+
+/// A rotation by `angle` about the line through `point` along `axis`,
+/// together with a translation by `distance` along that line.
+#[derive(Copy, Clone)]
+pub struct Screw<S: Field> {
+    pub point: Pnt<3, S>,
+    pub axis: Nrml<3, S>,
+    pub angle: S,
+    pub distance: S,
+}
+
+impl<S: Field> Screw<S> {
+    /// The distance moved along the axis per radian turned, which is infinite for a pure translation.
+    pub fn pitch(self) -> S {
+        self.distance.div(self.angle)
+    }
+}
+
+impl<S: Field> From<Screw<S>> for Rig<3, S> {
+    fn from(screw: Screw<S>) -> Self {
+        let rot = Rot::angle_axis(screw.angle, screw.axis);
+        Self {
+            trans: screw.point - rot.apl(screw.point) + screw.axis * screw.distance,
+            rot,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_exp_log() {
+        let twist = Vect([0.3, -1.2, 0.5, 0.2, 1.1, -2.]);
+        let rig = Rig::<3, f64>::exp(twist);
+        assert!((rig.log() - twist).magn() < 1e-9);
+
+        let other = Vect([0.1, -0.3, 0.2, 0.4, 0.1, -0.2]);
+        assert_close(
+            rig.aft(Rig::<3, f64>::exp(other)).to_hmat(),
+            Rig::<3, f64>::exp(rig.adjoint() * other).aft(rig).to_hmat(),
+            1e-9,
+        );
+
+        assert_close(
+            Rig::from(rig.screw().unwrap()).to_hmat(),
+            rig.to_hmat(),
+            1e-9,
+        );
+        assert_close(
+            Rig::<3, f64>::IDENT.sclerp(rig, 0.5).to_hmat(),
+            Rig::<3, f64>::exp(twist * 0.5).to_hmat(),
+            1e-9,
+        );
+
+        let twist = Vect([0.3, -1.2, 2.5]);
+        assert!((Rig::<2, f64>::exp(twist).log() - twist).magn() < 1e-9);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
-    Mat, Nrml, Vect,
+    Mat, Nrml, Pnt, Vect,
     op_wrapper::{Sc, scs},
-    ops::{Apl, BefAft},
+    ops::{Apl, BefAft, Cross, Det, Dot, ProjRej},
     traits::Field,
 };
 use std::ops::Mul;
@@ -13,8 +13,15 @@ use maybe_trait::Maybe;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 mod cross_product;
+mod euler;
+mod lie;
 mod rot2;
 mod rot3;
+mod rot4;
+mod rotn;
+
+pub use euler::{EulerAxes, EulerOrder};
+pub(crate) use lie::coefficients;
 
 pub trait RotInner<const N: usize, S: Field>: Sized + Copy {
     type Bivector;
@@ -50,6 +57,12 @@ pub trait RotInner<const N: usize, S: Field>: Sized + Copy {
     fn part(self, t: S) -> Self;
     fn inv(self) -> Self;
 
+    /// The same rotation, with the rotor negated.
+    fn neg(self) -> Self;
+
+    /// Interpolates the components of the rotors and renormalizes.
+    fn nlerp(self, other: Self, t: S) -> Self;
+
     fn aft(self, other: Self) -> Self;
 
     fn apl(self, vect: Vect<N, S>) -> Vect<N, S>;
@@ -61,6 +74,12 @@ pub trait RotInner<const N: usize, S: Field>: Sized + Copy {
 
 pub struct RotBivectorRepr<R>(R);
 
+/// The dimensions that have rotors, which are 2 through 8.
+///
+/// Stable Rust can't compute an array length like `N * (N - 1) / 2` from a const generic,
+/// so each dimension is implemented separately, and above 4 dimensions only as far as [`CliffordDim`] goes.
+///
+/// [`CliffordDim`]: crate::multivector::CliffordDim
 pub trait RotDim<const N: usize> {
     type Inner<S: Field>: RotInner<N, S>;
 }
@@ -147,6 +166,43 @@ where
         Self(self.0.inv())
     }
 
+    /// Either `other` or the same rotation with its rotor negated, whichever is nearer to `self`.
+    ///
+    /// Interpolating from `self` to the result takes the short way around.
+    pub fn nearest(self, other: Self) -> Self {
+        if self.inv().aft(other).w() < S::ZERO {
+            Self(other.0.neg())
+        } else {
+            other
+        }
+    }
+
+    /// Interpolates from `self` to `other` at a constant rate.
+    ///
+    /// This follows the rotors as given, which may be the long way around; see [`Self::nearest`].
+    pub fn slerp(self, other: Self, t: S) -> Self {
+        self.aft(self.inv().aft(other).part(t))
+    }
+
+    /// Interpolates the rotors directly and renormalizes.
+    ///
+    /// Cheaper than [`Self::slerp`], but not at a constant rate.
+    pub fn nlerp(self, other: Self, t: S) -> Self {
+        Self(self.0.nlerp(other.0, t))
+    }
+
+    /// Perturbs this rotation by `delta` in its own frame, giving `self.aft(Rot::from_torq(delta))`.
+    pub fn box_plus(self, delta: Bivector<N, S>) -> Self {
+        self.aft(Self::from_torq(delta))
+    }
+
+    /// The perturbation taking `other` to `self`, so that `other.box_plus(self.box_minus(other))` is `self`.
+    ///
+    /// This is the shorter of the two ways around.
+    pub fn box_minus(self, other: Self) -> Bivector<N, S> {
+        Self::IDENT.nearest(other.inv().aft(self)).to_torq()
+    }
+
     pub fn mat(self) -> Mat<N, N, S> {
         self.0.mat()
     }
@@ -172,6 +228,18 @@ where
     }
 }
 
+/// Rotates about the origin.
+impl<const N: usize, S: Field> Apl<Pnt<N, S>> for Rot<N, S>
+where
+    (): RotDim<N>,
+{
+    type Output = Pnt<N, S>;
+
+    fn apl(self, other: Pnt<N, S>) -> Self::Output {
+        Pnt::ORIGIN + self.apl(other - Pnt::ORIGIN)
+    }
+}
+
 impl<const N: usize, S: Field> Apl<Nrml<N, S>> for Rot<N, S>
 where
     (): RotDim<N>,
@@ -222,6 +290,131 @@ impl<S: Field> Rot<3, S> {
         unsafe { Self::from_w_bi_unchecked(cos.0, Vect::axis(2, sin.0)) }
     }
 
+    /// The rotation taking each axis to the matching column of `mat`.
+    ///
+    /// The rotor is extracted from whichever of the trace and the diagonal is largest,
+    /// so it stays accurate near half turns.  `mat` is assumed to be a rotation;
+    /// see [`Self::try_from_mat`] to check that it is.
+    #[culit]
+    pub fn from_mat(mat: Mat<3, 3, S>) -> Self {
+        let m = |i: usize, j: usize| Sc(mat[i][j]);
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+        let largest = [trace, m(0, 0), m(1, 1), m(2, 2)]
+            .into_iter()
+            .enumerate()
+            .fold((0, trace), |c, n| if n.1 > c.1 { n } else { c })
+            .0;
+        let wxyz = match largest {
+            0 => {
+                let r = (1Sc + trace).max(0Sc).sqrt();
+                let s = 0.5Sc / r;
+                [
+                    r * 0.5Sc,
+                    (m(2, 1) - m(1, 2)) * s,
+                    (m(0, 2) - m(2, 0)) * s,
+                    (m(1, 0) - m(0, 1)) * s,
+                ]
+            }
+            1 => {
+                let r = (1Sc + m(0, 0) - m(1, 1) - m(2, 2)).max(0Sc).sqrt();
+                let s = 0.5Sc / r;
+                [
+                    (m(2, 1) - m(1, 2)) * s,
+                    r * 0.5Sc,
+                    (m(0, 1) + m(1, 0)) * s,
+                    (m(0, 2) + m(2, 0)) * s,
+                ]
+            }
+            2 => {
+                let r = (1Sc - m(0, 0) + m(1, 1) - m(2, 2)).max(0Sc).sqrt();
+                let s = 0.5Sc / r;
+                [
+                    (m(0, 2) - m(2, 0)) * s,
+                    (m(0, 1) + m(1, 0)) * s,
+                    r * 0.5Sc,
+                    (m(1, 2) + m(2, 1)) * s,
+                ]
+            }
+            _ => {
+                let r = (1Sc - m(0, 0) - m(1, 1) + m(2, 2)).max(0Sc).sqrt();
+                let s = 0.5Sc / r;
+                [
+                    (m(1, 0) - m(0, 1)) * s,
+                    (m(0, 2) + m(2, 0)) * s,
+                    (m(1, 2) + m(2, 1)) * s,
+                    r * 0.5Sc,
+                ]
+            }
+        };
+
+        if let Some(normal) = Vect(wxyz.map(|x| x.0)).normal() {
+            let [w, x, y, z] = normal.array();
+            unsafe { Self::from_w_bi_unchecked(w, Vect([x, y, z])) }
+        } else {
+            Self::IDENT
+        }
+    }
+
+    /// Like [`Self::from_mat`], but `None` unless `mat` is a rotation,
+    /// meaning its columns are orthonormal to within `tolerance` and it has a positive determinant.
+    pub fn try_from_mat(mat: Mat<3, 3, S>, tolerance: S) -> Option<Self> {
+        for i in 0..3 {
+            for j in i..3 {
+                let dot = mat.col(i).dot(mat.col(j));
+                let expected = if i == j { S::ONE } else { S::ZERO };
+                if dot.sub(expected).abs() > tolerance {
+                    return None;
+                }
+            }
+        }
+        if mat.det() <= S::ZERO {
+            return None;
+        }
+        Some(Self::from_mat(mat))
+    }
+
+    /// The rotation taking the `x`, `y` and `z` axes to `x`, `y` and `z`,
+    /// which should be orthonormal and right-handed.
+    pub fn from_basis(x: Nrml<3, S>, y: Nrml<3, S>, z: Nrml<3, S>) -> Self {
+        let basis = [x, y, z];
+        Self::from_mat(Mat::from_fn(|i, j| basis[j][i]))
+    }
+
+    /// The rotation pointing `-z` along `forward`, with `y` as close to `up` as it can be.
+    /// This is how a camera is oriented, matching [`Mat::perspective_projection`].
+    ///
+    /// When `forward` is parallel to `up` the roll is ambiguous,
+    /// so this is the smallest rotation pointing `-z` along `forward`.
+    pub fn look_rotation(forward: Nrml<3, S>, up: Nrml<3, S>) -> Self {
+        if let Some(right) = forward.cross(up).normal()
+            && let Some(up) = up.rej(forward).normal()
+        {
+            Self::from_basis(right, up, -forward)
+        } else {
+            Self::from_to(-Nrml::axis(2), forward)
+        }
+    }
+
+    /// The part of this rotation about `axis`, as a rotation in the plane orthogonal to it.
+    ///
+    /// This undoes [`Rot::lift`], so `self.twist(axis).lift(axis)` is the twist of [`Self::swing_twist`].
+    pub fn twist(self, axis: Nrml<3, S>) -> Rot<2, S> {
+        if let Some(normal) = Vect([self.w(), self.bi().dot(axis)]).normal() {
+            unsafe { Rot::from_w_bi_unchecked(normal[0], Vect([normal[1]])) }
+        } else {
+            Rot::IDENT
+        }
+    }
+
+    /// Splits this rotation into a swing and a twist, such that `swing.aft(twist) == self`.
+    ///
+    /// The twist is about `axis`, and the swing is about an axis orthogonal to it.
+    /// When this rotation is a half turn about such an axis, the twist is ambiguous and is the identity.
+    pub fn swing_twist(self, axis: Nrml<3, S>) -> (Self, Self) {
+        let twist = Self(self.twist(axis).lift(axis));
+        (self.aft(twist.inv()), twist)
+    }
+
     #[culit]
     pub fn euler_angles(self) -> (S, S, S) {
         let w = self.w();
@@ -236,6 +429,30 @@ impl<S: Field> Rot<3, S> {
     }
 }
 
+impl<S: Field> Rot<4, S> {
+    /// Rotates by `angle_a` in `plane` and by `angle_b` in the plane orthogonal to it.
+    ///
+    /// `plane` should be a single plane, like `Nrml::axis(0)` for `xy`.
+    pub fn double(angle_a: S, angle_b: S, plane: Nrml<6, S>) -> Self {
+        Self::from_torq(plane * angle_a + rot4::dual(plane.into()) * angle_b)
+    }
+
+    /// The angles of the two orthogonal planes of rotation, largest first.
+    pub fn double_angles(self) -> (S, S) {
+        let torq = self.to_torq();
+        let sqr = torq.sqr_magn();
+        let pseudo = rot4::pseudo(torq).mul(S::TWO);
+        let sum = sqr.sub(pseudo).max(S::ZERO).sqrt();
+        let dif = sqr.add(pseudo).max(S::ZERO).sqrt();
+        (sum.add(dif).mul(S::HALF), sum.sub(dif).abs().mul(S::HALF))
+    }
+
+    /// The `xyzw` part of the rotor.
+    pub fn quad(self) -> S {
+        self.0.quad()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<const N: usize, S: Field> Serialize for Rot<N, S>
 where
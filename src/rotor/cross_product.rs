@@ -1,5 +1,9 @@
 use crate::{Vect, ops::Cross, traits::Field};
 
+// The cross product is the Hodge dual of the wedge product, `a.wedge(b).dual()`, which these write out.
+// Going through the wedge builds a bivector only to dualize it straight back,
+// so the closed forms stay for something this commonly used, and the tests check them against the wedge.
+
 impl<S: Field> Cross for Vect<2, S> {
     type Output = Vect<1, S>;
 
@@ -19,3 +23,18 @@ impl<S: Field> Cross for Vect<3, S> {
         ])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::Wedge;
+
+    #[test]
+    fn test_cross() {
+        // The cross product is the Hodge dual of the wedge product.
+        let (a, b) = (Vect([0.4, -2., 1.3]), Vect([-1.1, 0.2, 0.8]));
+        assert!((a.cross(b) - a.wedge(b).dual().vect()).magn() < 1e-12);
+        let (a, b) = (Vect([0.4, -2.]), Vect([-1.1, 0.2]));
+        assert!(f64::abs(a.cross(b)[0] - a.wedge(b).dual()[0]) < 1e-12);
+    }
+}
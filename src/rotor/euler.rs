@@ -0,0 +1,222 @@
+use culit::culit;
+
+use super::*;
+
+/// The axes of an Euler angle sequence, in order.
+///
+/// The first six are Tait–Bryan sequences, which use every axis,
+/// and the last six are proper Euler sequences, which repeat the first axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerAxes {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// An Euler angle convention.
+///
+/// `Intrinsic(XYZ)` rotates about `x`, then about the rotated `y`, then about the twice rotated `z`.
+/// `Extrinsic(XYZ)` rotates about `x`, then `y`, then `z`, all fixed in place,
+/// which is the same as `Intrinsic(ZYX)` with the angles reversed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    Intrinsic(EulerAxes),
+    Extrinsic(EulerAxes),
+}
+
+impl EulerAxes {
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+
+    pub fn is_proper(self) -> bool {
+        let [i, _, k] = self.axes();
+        i == k
+    }
+}
+
+impl EulerOrder {
+    /// Every convention is handled as an extrinsic one, which means reversing intrinsic ones.
+    fn extrinsic<T>(self, (a, b, c): (T, T, T)) -> ([usize; 3], (T, T, T)) {
+        match self {
+            Self::Intrinsic(axes) => {
+                let [i, j, k] = axes.axes();
+                ([k, j, i], (c, b, a))
+            }
+            Self::Extrinsic(axes) => (axes.axes(), (a, b, c)),
+        }
+    }
+
+    /// The world space axes that each angle turns about, at the given angles.
+    fn extrinsic_axes<S: Field>(self, angles: (S, S, S)) -> [Vect<3, S>; 3] {
+        let ([i, j, k], (_, b, c)) = self.extrinsic(angles);
+        let third = Rot::angle_axis(c, Nrml::axis(k));
+        let second = third.aft(Rot::angle_axis(b, Nrml::axis(j)));
+        [
+            second.apl(Vect::axis(i, S::ONE)),
+            third.apl(Vect::axis(j, S::ONE)),
+            Vect::axis(k, S::ONE),
+        ]
+    }
+
+    /// The angular velocity, in world space, of the rotation with these Euler angles
+    /// when they change at these rates.
+    ///
+    /// So over a short time `dt`, `rot` becomes `Rot::from_torq(velocity * dt).aft(rot)`.
+    pub fn angular_velocity<S: Field>(self, angles: (S, S, S), rates: (S, S, S)) -> Vect<3, S> {
+        let [u, v, w] = self.extrinsic_axes(angles);
+        let (_, (a, b, c)) = self.extrinsic(rates);
+        u * a + v * b + w * c
+    }
+
+    /// The rates of change of these Euler angles that produce the world space angular velocity.
+    ///
+    /// This is `None` in gimbal lock, where the rates are not unique.
+    pub fn euler_rates<S: Field>(
+        self,
+        angles: (S, S, S),
+        velocity: Vect<3, S>,
+    ) -> Option<(S, S, S)> {
+        let [u, v, w] = self.extrinsic_axes(angles);
+        let det = u.dot(v.cross(w));
+        if det.is_zero() {
+            return None;
+        }
+        let rates = (
+            velocity.dot(v.cross(w)).div(det),
+            velocity.dot(w.cross(u)).div(det),
+            velocity.dot(u.cross(v)).div(det),
+        );
+        Some(self.extrinsic(rates).1)
+    }
+}
+
+/// Brings an angle into `-π..π`.
+fn wrap<S: Field>(angle: S) -> S {
+    angle.add(S::PI).rem_euclid(S::PI.add(S::PI)).sub(S::PI)
+}
+
+impl<S: Field> Rot<3, S> {
+    /// The rotation by `a`, `b` and `c` about the axes of `order`, in order.
+    pub fn from_euler(order: EulerOrder, a: S, b: S, c: S) -> Self {
+        let ([i, j, k], (a, b, c)) = order.extrinsic((a, b, c));
+        Self::angle_axis(c, Nrml::axis(k))
+            .aft(Self::angle_axis(b, Nrml::axis(j)))
+            .aft(Self::angle_axis(a, Nrml::axis(i)))
+    }
+
+    /// The angles that [`Self::from_euler`] would take to make this rotation.
+    ///
+    /// The first and last angles are in `-π..π`.
+    /// The middle angle is in `0..π` for proper Euler sequences and `-π/2..π/2` for Tait–Bryan ones.
+    ///
+    /// In gimbal lock, where the first and last axes line up, only their combined angle matters.
+    /// The last angle is then zero, and the first angle does all the turning.
+    #[culit]
+    pub fn to_euler(self, order: EulerOrder) -> (S, S, S) {
+        let intrinsic = matches!(order, EulerOrder::Intrinsic(_));
+        let ([i, j, k], _) = order.extrinsic(((), (), ()));
+
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        let even = (i + 1) % 3 == j;
+
+        let w = Sc(self.w());
+        let q = self.bi().0.map(Sc);
+        let qk = if even { q[k] } else { -q[k] };
+        let (a, b, c, d) = if proper {
+            (w, q[i], q[j], qk)
+        } else {
+            (w - q[j], q[i] + qk, q[j] + w, qk - q[i])
+        };
+
+        let sum_sqr = (a.pow(2) + b.pow(2)).0;
+        let dif_sqr = (c.pow(2) + d.pow(2)).0;
+        let middle = 2Sc * Sc::atan2(Sc(dif_sqr.sqrt()), Sc(sum_sqr.sqrt()));
+        let half_sum = Sc::atan2(b, a);
+        let half_dif = Sc::atan2(d, c);
+
+        // In extrinsic order, the first angle is the last of an intrinsic sequence.
+        let (first, third) = if sum_sqr.add(dif_sqr) == sum_sqr {
+            if intrinsic {
+                (0Sc, 2Sc * half_sum)
+            } else {
+                (2Sc * half_sum, 0Sc)
+            }
+        } else if sum_sqr.add(dif_sqr) == dif_sqr {
+            if intrinsic {
+                (0Sc, 2Sc * half_dif)
+            } else {
+                (-2Sc * half_dif, 0Sc)
+            }
+        } else {
+            (half_sum - half_dif, half_sum + half_dif)
+        };
+
+        let (middle, third) = if proper {
+            (middle, third)
+        } else if even {
+            (middle - Sc(S::PI) * 0.5Sc, third)
+        } else {
+            (middle - Sc(S::PI) * 0.5Sc, -third)
+        };
+
+        order.extrinsic((wrap(first.0), middle.0, wrap(third.0))).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use EulerAxes::*;
+
+    #[test]
+    fn test_euler_round_trip() {
+        let rot = Rot::<3, f64>::from_torq(Vect([0.3, -1.2, 0.5]));
+        for axes in [XYZ, XZY, YXZ, YZX, ZXY, ZYX, XYX, XZX, YXY, YZY, ZXZ, ZYZ] {
+            for order in [EulerOrder::Intrinsic(axes), EulerOrder::Extrinsic(axes)] {
+                let (a, b, c) = rot.to_euler(order);
+                let back = Rot::from_euler(order, a, b, c);
+                assert!(
+                    (back.mat() - rot.mat())
+                        .0
+                        .iter()
+                        .flatten()
+                        .all(|x| x.abs() < 1e-9)
+                );
+
+                let velocity = order.angular_velocity((a, b, c), (0.2, -0.7, 1.1));
+                let (da, db, dc) = order.euler_rates((a, b, c), velocity).unwrap();
+                assert!((Vect([da, db, dc]) - Vect([0.2, -0.7, 1.1])).magn() < 1e-9);
+            }
+        }
+
+        let order = EulerOrder::Intrinsic(XYZ);
+        let locked = Rot::<3, f64>::from_euler(order, 0.4, std::f64::consts::FRAC_PI_2, 0.3);
+        let (a, _, c) = locked.to_euler(order);
+        assert!((a - 0.7).abs() < 1e-9 && c == 0.);
+    }
+}
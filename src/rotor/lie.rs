@@ -0,0 +1,155 @@
+use super::*;
+
+/// The coefficients `(1 - cos θ)/θ²`, `(θ - sin θ)/θ³` and `(θ²/2 - 1 + cos θ)/θ⁴`,
+/// from their power series when `θ` is small enough for the direct forms to lose precision.
+pub(crate) fn coefficients<S: Field>(angle: S) -> (S, S, S) {
+    let sqr = angle.pow(2);
+    let (c, g) = if angle < S::ONE {
+        // Sums `(-θ²)^k / (p + 2k)!`.
+        let series = |p: u32| {
+            let mut n = S::ZERO;
+            let mut term = S::ONE;
+            for _ in 0..p {
+                n.add_assign(S::ONE);
+                term = term.div(n);
+            }
+            let mut sum = term;
+            for _ in 0..12 {
+                n.add_assign(S::ONE);
+                term = term.neg().mul(sqr).div(n);
+                n.add_assign(S::ONE);
+                term = term.div(n);
+                sum.add_assign(term);
+            }
+            sum
+        };
+        (series(3), series(4))
+    } else {
+        let (sin, cos) = angle.sin_cos();
+        (
+            angle.sub(sin).div(sqr.mul(angle)),
+            sqr.mul(S::HALF).sub(S::ONE).add(cos).div(sqr.pow(2)),
+        )
+    };
+    (S::HALF.sub(sqr.mul(g)), c, g)
+}
+
+/// `SO(2)` is commutative, so its adjoint and Jacobians are all the identity.
+impl<S: Field> Rot<2, S> {
+    /// The skew matrix of `torq`, which is the derivative of `Rot::from_torq(torq * t).mat()` at zero.
+    pub fn hat(torq: Vect<1, S>) -> Mat<2, 2, S> {
+        let Vect([angle]) = torq;
+        Mat([[S::ZERO, angle.neg()], [angle, S::ZERO]])
+    }
+
+    /// Undoes [`Self::hat`], ignoring any symmetric part of `mat`.
+    pub fn vee(mat: Mat<2, 2, S>) -> Vect<1, S> {
+        Vect([mat[1][0].sub(mat[0][1]).mul(S::HALF)])
+    }
+
+    pub fn adjoint(self) -> Mat<1, 1, S> {
+        Mat::IDENT
+    }
+
+    pub fn right_jacobian(_torq: Vect<1, S>) -> Mat<1, 1, S> {
+        Mat::IDENT
+    }
+
+    pub fn left_jacobian(_torq: Vect<1, S>) -> Mat<1, 1, S> {
+        Mat::IDENT
+    }
+
+    pub fn right_jacobian_inv(_torq: Vect<1, S>) -> Mat<1, 1, S> {
+        Mat::IDENT
+    }
+
+    pub fn left_jacobian_inv(_torq: Vect<1, S>) -> Mat<1, 1, S> {
+        Mat::IDENT
+    }
+}
+
+impl<S: Field> Rot<3, S> {
+    /// The skew matrix of `torq`, such that `Rot::hat(torq) * v` is `torq.cross(v)`.
+    pub fn hat(torq: Vect<3, S>) -> Mat<3, 3, S> {
+        let Vect([x, y, z]) = torq;
+        let zero = S::ZERO;
+        Mat([[zero, z.neg(), y], [z, zero, x.neg()], [y.neg(), x, zero]])
+    }
+
+    /// Undoes [`Self::hat`], ignoring any symmetric part of `mat`.
+    pub fn vee(mat: Mat<3, 3, S>) -> Vect<3, S> {
+        Vect([
+            mat[2][1].sub(mat[1][2]),
+            mat[0][2].sub(mat[2][0]),
+            mat[1][0].sub(mat[0][1]),
+        ]) * S::HALF
+    }
+
+    /// Takes perturbations on the right of this rotation to ones on the left,
+    /// so that `self.aft(Rot::from_torq(torq))` is `Rot::from_torq(self.adjoint() * torq).aft(self)`.
+    pub fn adjoint(self) -> Mat<3, 3, S> {
+        self.mat()
+    }
+
+    /// How `Rot::from_torq(torq)` changes with `torq`, as a perturbation on the right.
+    ///
+    /// `Rot::from_torq(torq + delta)` is about `Rot::from_torq(torq).box_plus(Rot::right_jacobian(torq) * delta)`.
+    pub fn right_jacobian(torq: Vect<3, S>) -> Mat<3, 3, S> {
+        let (b, c, _) = coefficients(torq.magn());
+        let hat = Self::hat(torq);
+        Mat::IDENT - hat * b + hat * hat * c
+    }
+
+    /// How `Rot::from_torq(torq)` changes with `torq`, as a perturbation on the left.
+    pub fn left_jacobian(torq: Vect<3, S>) -> Mat<3, 3, S> {
+        Self::right_jacobian(-torq)
+    }
+
+    /// The inverse of [`Self::right_jacobian`], which is how `to_torq` changes
+    /// with a perturbation on the right.  Singular at full turns.
+    pub fn right_jacobian_inv(torq: Vect<3, S>) -> Mat<3, 3, S> {
+        let (b, c, g) = coefficients(torq.magn());
+        let d = c.sub(g.mul(S::TWO)).div(b.mul(S::TWO));
+        let hat = Self::hat(torq);
+        Mat::IDENT + hat * S::HALF + hat * hat * d
+    }
+
+    /// The inverse of [`Self::left_jacobian`].
+    pub fn left_jacobian_inv(torq: Vect<3, S>) -> Mat<3, 3, S> {
+        Self::right_jacobian_inv(-torq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_jacobians() {
+        for torq in [Vect([0.3, -1.2, 0.5]), Vect([1e-4, 2e-4, -1e-4])] {
+            let rot = Rot::<3, f64>::from_torq(torq);
+            let jr = Rot::<3, f64>::right_jacobian(torq);
+            assert_close(
+                jr * Rot::<3, f64>::right_jacobian_inv(torq),
+                Mat::IDENT,
+                1e-9,
+            );
+            assert_close(Rot::<3, f64>::left_jacobian(torq), rot.adjoint() * jr, 1e-9);
+
+            let delta = Vect([0.1, 0.2, -0.3]);
+            assert!((rot.box_plus(delta).box_minus(rot) - delta).magn() < 1e-9);
+            assert!((Rot::<3, f64>::hat(torq) * delta - torq.cross(delta)).magn() < 1e-12);
+            assert!((Rot::<3, f64>::vee(Rot::<3, f64>::hat(torq)) - torq).magn() < 1e-12);
+        }
+
+        let (torq, delta) = (Vect([2.9]), Vect([0.4]));
+        let moved = Rot::<2, f64>::from_torq(torq + delta);
+        let jr = Rot::<2, f64>::right_jacobian(torq);
+        let jl = Rot::<2, f64>::left_jacobian(torq);
+        assert!((moved.box_minus(Rot::from_torq(torq)) - jr * delta).magn() < 1e-9);
+        assert_eq!(jl, Rot::<2, f64>::from_torq(torq).adjoint() * jr);
+        assert_eq!(jr * Rot::<2, f64>::right_jacobian_inv(torq), Mat::IDENT);
+        assert_eq!(jl * Rot::<2, f64>::left_jacobian_inv(torq), Mat::IDENT);
+    }
+}
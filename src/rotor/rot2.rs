@@ -64,10 +64,9 @@ impl<S: Field> RotInner<2, S> for RotInner2<S> {
         Self(w, bi)
     }
 
-    #[culit]
+    /// From `0` to `2π`, by `atan2`, which keeps its precision for small angles where `acos` doesn't.
     fn angle(self) -> S {
-        let w = Sc(self.0).clamp(-1Sc, 1Sc);
-        (2Sc * w.acos()).0
+        S::atan2(self.1.magn(), self.0).mul(S::TWO)
     }
 
     fn axis(self) -> Option<Self::Axis> {
@@ -102,6 +101,18 @@ impl<S: Field> RotInner<2, S> for RotInner2<S> {
         Self(self.0, -self.1)
     }
 
+    fn neg(self) -> Self {
+        Self(self.0.neg(), -self.1)
+    }
+
+    fn nlerp(self, other: Self, t: S) -> Self {
+        Self(
+            self.0.add(other.0.sub(self.0).mul(t)),
+            self.1 + (other.1 - self.1) * t,
+        )
+        .reunitize()
+    }
+
     fn aft(self, other: Self) -> Self {
         Self(
             self.0.mul(other.0).sub(self.1.dot(other.1)),
@@ -120,9 +131,39 @@ impl<S: Field> RotInner<2, S> for RotInner2<S> {
         vector.normal()
     }
 
+    #[culit]
     fn mat(self) -> Mat<2, 2, S> {
-        let Self(cos, Vect([sin])) = self;
-        scs!(cos, sin);
+        let Self(w, Vect([b])) = self;
+        scs!(w, b);
+        let cos = w.pow(2) - b.pow(2);
+        let sin = 2Sc * w * b;
         Mat::from_scs([[cos, -sin], [sin, cos]])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_mat() {
+        for angle in [0.3, -1.2, 2.9, -3.1] {
+            let mat = Rot::<2, f64>::angle2(angle).mat();
+            let (sin, cos) = f64::sin_cos(angle);
+            assert_close(mat, Mat([[cos, -sin], [sin, cos]]), 1e-12);
+            let v = Vect([0.4, -2.]);
+            assert!((mat * v - Rot::<2, f64>::angle2(angle).apl(v)).magn() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_angle() {
+        let rot = Rot::<2, f64>::angle2(-0.5);
+        assert!((rot.angle() - 0.5).abs() < 1e-12);
+        assert!((rot.signed_angle() + 0.5).abs() < 1e-12);
+        let far = Rot::<2, f64>(rot.0.neg());
+        assert!((far.angle() - (2. * std::f64::consts::PI - 0.5)).abs() < 1e-12);
+        assert!((Rot::<2, f64>::angle2(1e-9).angle() - 1e-9).abs() < 1e-18);
+    }
+}
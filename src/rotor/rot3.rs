@@ -2,10 +2,7 @@ use culit::culit;
 
 use super::*;
 
-use crate::{
-    op_wrapper::{Sc, scs},
-    ops::{Cross as _, Dot as _},
-};
+use crate::op_wrapper::{Sc, scs};
 
 impl RotDim<3> for () {
     type Inner<S: Field> = RotInner3<S>;
@@ -68,10 +65,9 @@ impl<S: Field> RotInner<3, S> for RotInner3<S> {
         Self(w, bi)
     }
 
-    #[culit]
+    /// From `0` to `2π`, by `atan2`, which keeps its precision for small angles where `acos` doesn't.
     fn angle(self) -> S {
-        let w = Sc(self.0).clamp(-1Sc, 1Sc);
-        (2Sc * (w.acos())).0
+        S::atan2(self.1.magn(), self.0).mul(S::TWO)
     }
 
     fn axis(self) -> Option<Self::Axis> {
@@ -106,6 +102,18 @@ impl<S: Field> RotInner<3, S> for RotInner3<S> {
         Self(self.0, -self.1)
     }
 
+    fn neg(self) -> Self {
+        Self(self.0.neg(), -self.1)
+    }
+
+    fn nlerp(self, other: Self, t: S) -> Self {
+        Self(
+            self.0.add(other.0.sub(self.0).mul(t)),
+            self.1 + (other.1 - self.1) * t,
+        )
+        .renormalize()
+    }
+
     fn aft(self, other: Self) -> Self {
         Self(
             self.0.mul(other.0).sub(self.1.dot(other.1)),
@@ -151,4 +159,86 @@ mod tests {
             panic!("{v2} != {}", q.apl(Vect::from(v1)));
         }
     }
+
+    #[test]
+    fn test_angle() {
+        let torq = Vect([0.3, -1.2, 0.5]);
+        let rot = Rot::<3, f64>::from_torq(torq);
+        assert!((rot.angle() - torq.magn()).abs() < 1e-12);
+        assert!((rot.inv().angle() - torq.magn()).abs() < 1e-12);
+        let far = Rot::<3, f64>(rot.0.neg());
+        assert!((far.angle() - (2. * std::f64::consts::PI - torq.magn())).abs() < 1e-12);
+        assert_eq!(Rot::<3, f64>::IDENT.angle(), 0.);
+        let tiny = Rot::<3, f64>::from_torq(Vect([1e-9, 0., 0.]));
+        assert!((tiny.angle() - 1e-9).abs() < 1e-18);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let a = Rot::<3, f64>::yaw(0.3);
+        let b = Rot::<3, f64>::yaw(-2.5);
+        let far = Rot(b.0.neg());
+        for rot in [
+            a.slerp(b, 0.5),
+            a.nlerp(b, 0.5),
+            a.slerp(a.nearest(far), 0.5),
+        ] {
+            assert!((rot.to_torq() - Vect([0., -1.1, 0.])).magn() < 1e-9);
+        }
+        let long = (0.3 - 2.5) / 2. + std::f64::consts::PI;
+        assert!((a.slerp(far, 0.5).angle() - long).abs() < 1e-9);
+
+        let x = Nrml::<3, f64>::axis(0);
+        let z = Nrml::axis(2);
+        let mid = x.slerp(z, 1. / 3.);
+        assert!((mid.dot(x) - 0.75f64.sqrt()).abs() < 1e-9);
+        assert!((x.slerp(-x, 0.5).dot(x)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_mat() {
+        for torq in [
+            Vect([0.3, -1.2, 0.5]),
+            Vect([3.1, 0.1, -0.05]),
+            Vect([0., 0., -3.13]),
+            Vect([0., 0., 0.]),
+        ] {
+            let rot = Rot::<3, f64>::from_torq(torq);
+            let back = Rot::try_from_mat(rot.mat(), 1e-9).unwrap();
+            let v = Vect([0.4, -2., 1.3]);
+            assert!((back.apl(v) - rot.apl(v)).magn() < 1e-9);
+        }
+
+        let rot = Rot::<3, f64>::from_torq(Vect([0.3, -1.2, 0.5]));
+        assert!(Rot::try_from_mat(rot.mat() * 1.1, 1e-6).is_none());
+        assert!(Rot::try_from_mat(-rot.mat(), 1e-6).is_none());
+
+        let [x, y, z] = [0, 1, 2].map(|i| rot.apl(Nrml::axis(i)));
+        assert!((Rot::from_basis(x, y, z).to_torq() - rot.to_torq()).magn() < 1e-9);
+    }
+
+    #[test]
+    fn test_look_rotation() {
+        let forward: Nrml<3, f64> = Vect([1., -2., 0.5]).normal().unwrap();
+        let up = Nrml::axis(1);
+        let rot = Rot::look_rotation(forward, up);
+        assert!((rot.apl(Vect([0., 0., -1.])) - Vect::from(forward)).magn() < 1e-9);
+        assert!(rot.apl(Vect([1., 0., 0.]))[1].abs() < 1e-9);
+        assert!(rot.apl(Vect([0., 1., 0.]))[1] > 0.);
+
+        let rot = Rot::look_rotation(-up, up);
+        assert!((rot.apl(Vect([0., 0., -1.])) - Vect::from(-up)).magn() < 1e-9);
+    }
+
+    #[test]
+    fn test_swing_twist() {
+        let axis: Nrml<3, f64> = Vect([0.3, 1., -0.2]).normal().unwrap();
+        let rot = Rot::<3, f64>::from_torq(Vect([0.3, -1.2, 0.5]));
+        let (swing, twist) = rot.swing_twist(axis);
+        let v = Vect([0.4, -2., 1.3]);
+        assert!((swing.aft(twist).apl(v) - rot.apl(v)).magn() < 1e-9);
+        assert!(swing.bi().dot(axis).abs() < 1e-9);
+        assert!(twist.bi().cross(axis).magn() < 1e-9);
+        assert!((rot.twist(axis).signed_angle() - twist.to_torq().dot(axis)).abs() < 1e-9);
+    }
 }
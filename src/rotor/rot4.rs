@@ -0,0 +1,299 @@
+use culit::culit;
+
+use super::*;
+
+use crate::{
+    op_wrapper::{Sc, scs},
+    ops::Wedge as _,
+};
+
+impl RotDim<4> for () {
+    type Inner<S: Field> = RotInner4<S>;
+}
+
+/// The bivector is ordered `xy, xz, xw, yz, yw, zw`, and the last field is the `xyzw` part,
+/// which is only nonzero for double rotations.
+#[derive(Copy, Clone)]
+pub struct RotInner4<S: Field>(S, Vect<6, S>, S);
+
+/// The bivector of the planes orthogonal to those of `bi`.
+pub(super) fn dual<S: Field>(bi: Vect<6, S>) -> Vect<6, S> {
+    let Vect([xy, xz, xw, yz, yw, zw]) = bi;
+    Vect([zw.neg(), yw, yz.neg(), xw.neg(), xz, xy.neg()])
+}
+
+/// Half the `xyzw` part of the square of `bi`.  Zero exactly when `bi` is a single plane.
+pub(super) fn pseudo<S: Field>(bi: Vect<6, S>) -> S {
+    let Vect([xy, xz, xw, yz, yw, zw]) = bi;
+    scs!(xy, xz, xw, yz, yw, zw);
+    (xy * zw - xz * yw + xw * yz).0
+}
+
+fn commutator<S: Field>(a: Vect<6, S>, b: Vect<6, S>) -> Vect<6, S> {
+    let Vect([a0, a1, a2, a3, a4, a5]) = a;
+    let Vect([b0, b1, b2, b3, b4, b5]) = b;
+    scs!(a0, a1, a2, a3, a4, a5, b0, b1, b2, b3, b4, b5);
+    Vect([
+        (a1 * b3 + a2 * b4 - a3 * b1 - a4 * b2).0,
+        (a2 * b5 + a3 * b0 - a0 * b3 - a5 * b2).0,
+        (a4 * b0 + a5 * b1 - a0 * b4 - a1 * b5).0,
+        (a0 * b1 + a4 * b5 - a1 * b0 - a5 * b4).0,
+        (a0 * b2 + a5 * b3 - a2 * b0 - a3 * b5).0,
+        (a1 * b2 + a3 * b4 - a2 * b1 - a4 * b3).0,
+    ])
+}
+
+impl<S: Field> RotInner4<S> {
+    pub(super) fn quad(self) -> S {
+        self.2
+    }
+
+    /// Every four-dimensional rotor splits into a left-isoclinic and a right-isoclinic half,
+    /// each of which behaves like a quaternion.  This puts the two halves back together.
+    fn from_halves((w_l, bi_l): (S, Vect<6, S>), (w_r, bi_r): (S, Vect<6, S>)) -> Self {
+        Self(
+            w_l.add(w_r).mul(S::HALF),
+            (bi_l + bi_r + dual(bi_l - bi_r)) * S::HALF,
+            w_l.sub(w_r).mul(S::HALF),
+        )
+    }
+
+    fn renormalize(self) -> Self {
+        let Self(w, bi, p) = self;
+        let sqr = bi.sqr_magn();
+        let pseudo = pseudo(bi).mul(S::TWO);
+        let magn_l = w.add(p).pow(2).add(sqr).sub(pseudo).max(S::ZERO).sqrt();
+        let magn_r = w.sub(p).pow(2).add(sqr).add(pseudo).max(S::ZERO).sqrt();
+        if magn_l.is_zero() || magn_r.is_zero() {
+            return Self::IDENT;
+        }
+        Self::from_halves(
+            (w.add(p).div(magn_l), bi / magn_l),
+            (w.sub(p).div(magn_r), bi / magn_r),
+        )
+    }
+}
+
+impl<S: Field> RotInner<4, S> for RotInner4<S> {
+    type Bivector = Vect<6, S>;
+    type Axis = Nrml<6, S>;
+
+    const IDENT: Self = Self(S::ONE, Vect::ZERO, S::ZERO);
+
+    fn angle_axis(angle: S, axis: Nrml<6, S>) -> Self {
+        Self::from_torq(axis * angle)
+    }
+
+    fn from_to(from: Nrml<4, S>, to: Nrml<4, S>) -> Self {
+        let dot = to.dot(from);
+        let wedge = Vect::from(from).wedge(to.into()).bivector();
+        if wedge == Vect::ZERO {
+            //This all handles the edge case where from == -to
+            if dot > S::ZERO {
+                return Self::IDENT;
+            }
+
+            if let Some(plane) = Vect::from(from)
+                .wedge(Vect::axis(0, S::ONE))
+                .bivector()
+                .normal()
+            {
+                return Self(S::ZERO, plane.into(), S::ZERO);
+            } else {
+                // `from` is along x, so it is turned in the plane it makes with y.
+                let plane = Vect::from(from).wedge(Vect::axis(1, S::ONE)).bivector();
+                return Self(S::ZERO, plane.normal_or_zero(), S::ZERO);
+            }
+        }
+
+        let sqrt = dot.add(S::ONE).max(S::ZERO).sqrt();
+        Self(sqrt.div(S::SQRT_2), wedge / sqrt.mul(S::SQRT_2), S::ZERO)
+    }
+
+    fn from_torq(torq: Self::Bivector) -> Self {
+        let bi = torq * S::HALF;
+        let sqr = bi.sqr_magn();
+        let pseudo = pseudo(bi).mul(S::TWO);
+        let half = |sqr_angle: S| {
+            let angle = sqr_angle.max(S::ZERO).sqrt();
+            let (sin, cos) = angle.sin_cos();
+            if angle.is_zero() {
+                (cos, bi)
+            } else {
+                (cos, bi * sin.div(angle))
+            }
+        };
+        Self::from_halves(half(sqr.sub(pseudo)), half(sqr.add(pseudo)))
+    }
+
+    /// The `xyzw` part is recovered from the normalization,
+    /// so it is lost when `w` is zero.
+    unsafe fn from_w_bi_unchecked(w: S, bi: Self::Bivector) -> Self {
+        let p = S::ONE.sub(w.pow(2)).sub(bi.sqr_magn()).max(S::ZERO).sqrt();
+        Self(w, bi, p.mul(pseudo(bi).mul(w).sign()))
+    }
+
+    fn angle(self) -> S {
+        self.to_torq().magn()
+    }
+
+    fn axis(self) -> Option<Self::Axis> {
+        self.to_torq().normal()
+    }
+
+    fn axis_or_zero(self) -> Self::Bivector {
+        self.to_torq().normal_or_zero()
+    }
+
+    fn w(self) -> S {
+        self.0
+    }
+
+    fn bi(self) -> Self::Bivector {
+        self.1
+    }
+
+    fn to_torq(self) -> Self::Bivector {
+        let Self(w, bi, p) = self;
+        let sqr = bi.sqr_magn();
+        let pseudo = pseudo(bi).mul(S::TWO);
+        let half = |cos: S, sqr_sin: S| {
+            let sin = sqr_sin.max(S::ZERO).sqrt();
+            if !sin.is_zero() {
+                bi * S::atan2(sin, cos).div(sin)
+            } else if cos < S::ZERO {
+                // A half turn of this half, any plane will do.
+                Vect::axis(0, S::PI)
+            } else {
+                Vect::ZERO
+            }
+        };
+        let left = half(w.add(p), sqr.sub(pseudo));
+        let right = half(w.sub(p), sqr.add(pseudo));
+        left + right + dual(left - right)
+    }
+
+    fn part(self, t: S) -> Self {
+        Self::from_torq(self.to_torq() * t)
+    }
+
+    fn inv(self) -> Self {
+        Self(self.0, -self.1, self.2)
+    }
+
+    fn neg(self) -> Self {
+        Self(self.0.neg(), -self.1, self.2.neg())
+    }
+
+    /// Each isoclinic half is interpolated like a quaternion.
+    fn nlerp(self, other: Self, t: S) -> Self {
+        Self(
+            self.0.add(other.0.sub(self.0).mul(t)),
+            self.1 + (other.1 - self.1) * t,
+            self.2.add(other.2.sub(self.2).mul(t)),
+        )
+        .renormalize()
+    }
+
+    fn aft(self, other: Self) -> Self {
+        let Self(w1, a, p1) = self;
+        let Self(w2, b, p2) = other;
+        Self(
+            w1.mul(w2).add(p1.mul(p2)).sub(a.dot(b)),
+            b * w1 + a * w2 + dual(b) * p1 + dual(a) * p2 + commutator(a, b),
+            w1.mul(p2).add(p1.mul(w2)).sub(a.dot(dual(b))),
+        )
+        .renormalize()
+    }
+
+    fn apl(self, vect: Vect<4, S>) -> Vect<4, S> {
+        self.mat() * vect
+    }
+
+    fn normalize_bivector(vector: Self::Bivector) -> Option<Self::Axis> {
+        vector.normal()
+    }
+
+    #[culit]
+    fn mat(self) -> Mat<4, 4, S> {
+        let Self(w, Vect([xy, xz, xw, yz, yw, zw]), p) = self;
+        scs!(w, xy, xz, xw, yz, yw, zw, p);
+
+        let diag = |pos: [Sc<S>; 3], neg: [Sc<S>; 3]| {
+            let [a, b, c] = pos;
+            let [d, e, f] = neg;
+            w.pow(2) + a.pow(2) + b.pow(2) + c.pow(2) - d.pow(2) - e.pow(2) - f.pow(2) - p.pow(2)
+        };
+
+        Mat::from_scs([
+            [
+                diag([yz, yw, zw], [xy, xz, xw]),
+                -2Sc * (xy * w + xz * yz + xw * yw + zw * p),
+                2Sc * (xy * yz - xz * w - xw * zw + yw * p),
+                2Sc * (xy * yw + xz * zw - xw * w - yz * p),
+            ],
+            [
+                2Sc * (xy * w - xz * yz - xw * yw + zw * p),
+                diag([xz, xw, zw], [xy, yz, yw]),
+                -2Sc * (xy * xz + xw * p + yz * w + yw * zw),
+                2Sc * (xz * p + yz * zw - xy * xw - yw * w),
+            ],
+            [
+                2Sc * (xy * yz + xz * w - xw * zw - yw * p),
+                2Sc * (xw * p + yz * w - xy * xz - yw * zw),
+                diag([xy, xw, yw], [xz, yz, zw]),
+                -2Sc * (xy * p + xz * xw + yz * yw + zw * w),
+            ],
+            [
+                2Sc * (xy * yw + xz * zw + xw * w + yz * p),
+                2Sc * (yz * zw + yw * w - xy * xw - xz * p),
+                2Sc * (xy * p + zw * w - xz * xw - yz * yw),
+                diag([xy, xz, yz], [xw, yw, zw]),
+            ],
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_double_rotation() {
+        let (a, b) = (0.7, -2.1);
+        let rot = Rot::<4, f64>::double(a, b, Nrml::axis(0));
+        let x: Vect<4, f64> = Vect([1., 0., 0., 0.]);
+        let z: Vect<4, f64> = Vect([0., 0., 1., 0.]);
+        assert_close(rot.apl(x), Vect([a.cos(), a.sin(), 0., 0.]), 1e-9);
+        assert_close(rot.apl(z), Vect([0., 0., b.cos(), -b.sin()]), 1e-9);
+        let (big, small) = rot.double_angles();
+        assert_close(Vect([big, small]), Vect([-b, a]), 1e-9);
+
+        let torq = Vect([0.3, -1.2, 0.5, 2.2, -0.4, 0.9]);
+        assert_close(Rot::<4, f64>::from_torq(torq).to_torq(), torq, 1e-9);
+    }
+
+    #[test]
+    fn test_aft_and_from_to() {
+        let r1 = Rot::<4, f64>::from_torq(Vect([0.3, -1.2, 0.5, 2.2, -0.4, 0.9]));
+        let r2 = Rot::<4, f64>::from_torq(Vect([-1.1, 0.2, 0.8, -0.3, 1.4, 0.6]));
+        let v = Vect([0.4, -2., 1.3, 0.7]);
+        assert_close(r1.aft(r2).apl(v), r1.apl(r2.apl(v)), 1e-9);
+        assert_close(r1.inv().apl(r1.apl(v)), v, 1e-9);
+
+        let from: Nrml<4, f64> = Vect([-2.34, 5.8, -0.8, 1.1]).normal().unwrap();
+        let to = Vect([-8.2, 1.1, 4., -0.5]).normal().unwrap();
+        assert_close(
+            Rot::from_to(from, to).apl(Vect::from(from)),
+            to.into(),
+            1e-9,
+        );
+
+        for i in 0..4 {
+            let axis = Nrml::<4, f64>::axis(i);
+            let rot = Rot::from_to(axis, -axis);
+            assert_close(rot.apl(Vect::from(axis)), -Vect::from(axis), 1e-9);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+use crate::{
+    multivector::{CliffordDim, Multivector},
+    ops::Wedge as _,
+};
+
+macro_rules! rot_dims {
+    ($($n:literal: $b:literal),*) => {$(
+        impl RotDim<$n> for () {
+            type Inner<S: Field> = RotInnerN<$n, $b, S>;
+        }
+    )*}
+}
+
+rot_dims!(5: 10, 6: 15, 7: 21, 8: 28);
+
+/// A rotor in 5 to 8 dimensions, stored as the even part of a [`Multivector`].
+///
+/// `B` is the number of planes, `N * (N - 1) / 2`.
+/// Bivectors are ordered `xy, xz, .., yz, ..`, like in four dimensions.
+#[derive(Copy, Clone)]
+pub struct RotInnerN<const N: usize, const B: usize, S: Field>(Multivector<N, S>)
+where
+    (): CliffordDim<N>;
+
+/// The inverse square root of a product of a rotor-like multivector with its reverse.
+///
+/// Such products commute with each other,
+/// so this is the usual Newton iteration done in the algebra.
+fn inv_sqrt<const N: usize, S: Field>(x: Multivector<N, S>) -> Option<Multivector<N, S>>
+where
+    (): CliffordDim<N>,
+{
+    let bound = x.blades().iter().fold(S::ZERO, |c, n| c.add(n.abs()));
+    let three = S::TWO.add(S::ONE);
+    let mut y = Multivector::scalar(S::ONE.div(bound.sqrt()));
+    let mut residual = S::INFINITY;
+    for _ in 0..100 {
+        let next = y * (Multivector::scalar(three) - x * y * y) * S::HALF;
+        let next_residual = (x * next * next - Multivector::ONE).magn();
+        if next_residual.partial_cmp(&residual) != Some(Ordering::Less) {
+            break;
+        }
+        (y, residual) = (next, next_residual);
+    }
+    if residual < S::HALF { Some(y) } else { None }
+}
+
+/// `acos(c) / sqrt(1 - c^2)`, the angle over its sine.
+fn angle_over_sin<S: Field>(cos: S) -> S {
+    let d = S::ONE.sub(cos);
+    if d > S::HALF.pow(2) {
+        return cos.acos().div(S::ONE.sub(cos.pow(2)).sqrt());
+    }
+    // 1 + d/3 (1 + 2d/5 (1 + 3d/7 (..)))
+    let mut k = S::ZERO;
+    let mut terms = [(S::ZERO, S::ZERO); 16];
+    for term in &mut terms {
+        k.add_assign(S::ONE);
+        *term = (k, k.add(k).add(S::ONE));
+    }
+    terms.into_iter().rev().fold(S::ONE, |acc, (k, odd)| {
+        S::ONE.add(acc.mul(k).mul(d).div(odd))
+    })
+}
+
+fn outer<const N: usize, S: Field>(a: Vect<N, S>, b: Vect<N, S>) -> Mat<N, N, S> {
+    Mat::from_fn(|i, j| a[i].mul(b[j]))
+}
+
+impl<const N: usize, const B: usize, S: Field> RotInnerN<N, B, S>
+where
+    (): CliffordDim<N>,
+{
+    fn renormalize(self) -> Self {
+        let even = self.0.even();
+        if let Some(y) = inv_sqrt(even.rev() * even) {
+            Self(even * y)
+        } else {
+            Self::IDENT
+        }
+    }
+}
+
+impl<const N: usize, const B: usize, S: Field> RotInner<N, S> for RotInnerN<N, B, S>
+where
+    (): CliffordDim<N>,
+{
+    type Bivector = Vect<B, S>;
+    type Axis = Nrml<B, S>;
+
+    const IDENT: Self = Self(Multivector::ONE);
+
+    fn angle_axis(angle: S, axis: Nrml<B, S>) -> Self {
+        Self::from_torq(axis * angle)
+    }
+
+    fn from_to(from: Nrml<N, S>, to: Nrml<N, S>) -> Self {
+        let dot = to.dot(from);
+        let wedge = Vect::from(to).wedge(from.into());
+        if wedge == Multivector::ZERO {
+            //This all handles the edge case where from == -to
+            if dot > S::ZERO {
+                return Self::IDENT;
+            }
+
+            let axis = Vect::axis(if from[0].abs() < S::HALF { 0 } else { 1 }, S::ONE);
+            let plane = axis.wedge(from.into());
+            return Self(plane * S::ONE.div(plane.magn()));
+        }
+
+        let sqrt = dot.add(S::ONE).max(S::ZERO).sqrt();
+        Self((Multivector::scalar(dot.add(S::ONE)) + wedge) * S::ONE.div(sqrt.mul(S::SQRT_2)))
+    }
+
+    fn from_torq(torq: Self::Bivector) -> Self {
+        Self((Multivector::from_bivector(torq) * S::HALF.neg()).exp()).renormalize()
+    }
+
+    /// The parts of grade four and above are taken to be zero,
+    /// which is only true for rotations in a single plane.
+    unsafe fn from_w_bi_unchecked(w: S, bi: Self::Bivector) -> Self {
+        Self(Multivector::scalar(w) - Multivector::from_bivector(bi))
+    }
+
+    fn angle(self) -> S {
+        self.to_torq().magn()
+    }
+
+    fn axis(self) -> Option<Self::Axis> {
+        self.to_torq().normal()
+    }
+
+    fn axis_or_zero(self) -> Self::Bivector {
+        self.to_torq().normal_or_zero()
+    }
+
+    fn w(self) -> S {
+        self.0[0]
+    }
+
+    fn bi(self) -> Self::Bivector {
+        -self.0.bivector()
+    }
+
+    /// The logarithm is taken from the rotation matrix,
+    /// whose symmetric part holds the cosines of the angles and whose skew part holds the sines.
+    fn to_torq(self) -> Self::Bivector {
+        let mat = self.mat();
+        let trans = mat.transpose();
+        let (sym, skew) = ((mat + trans) * S::HALF, (mat - trans) * S::HALF);
+        let eigen = sym.sym_eigen().sorted();
+        let (cos, vecs) = (eigen.values, eigen.vectors);
+        let col = |i: usize| vecs.col(i);
+
+        // Planes turned by close to a half turn have tiny sines,
+        // so their angles are measured directly instead.
+        let near_half_turn = S::HALF.pow(4).sub(S::ONE);
+        let mut len = 0;
+        while len + 1 < N && cos[len + 1] < near_half_turn {
+            len += 2;
+        }
+
+        let mut log = skew
+            * (len..N).fold(Mat::ZERO, |c, i| {
+                c + outer(col(i), col(i)) * angle_over_sin(cos[i])
+            });
+
+        let mut basis: [Vect<N, S>; N] = std::array::from_fn(col);
+        let mut largest = None;
+        while len >= 2 {
+            let u = basis[0];
+            let image = mat * u;
+            let within = (1..len).fold(Vect::ZERO, |c, k| c + basis[k] * basis[k].dot(image));
+            let v = if within.magn() > S::HALF.pow(8) {
+                within.normal_or_zero()
+            } else {
+                basis[1]
+            };
+            log += (outer(v, u) - outer(u, v)) * S::atan2(v.dot(image), u.dot(image));
+            largest.get_or_insert((u, v));
+
+            // The remaining basis spans what is left once u and v are taken out.
+            let drop = (1..len)
+                .max_by(|&i, &j| basis[i].dot(v).abs().cmp(&basis[j].dot(v).abs()))
+                .unwrap();
+            let mut rest = 0;
+            for k in (1..len).filter(|&k| k != drop) {
+                let b = (0..rest).fold(basis[k].rej(v), |b, r| b - basis[r] * basis[r].dot(b));
+                basis[rest] = b.normal_or_zero();
+                rest += 1;
+            }
+            len = rest;
+        }
+
+        let mut torq = Vect::ZERO;
+        let mut plane = 0;
+        for i in 0..N {
+            for j in i + 1..N {
+                torq[plane] = log[j][i];
+                plane += 1;
+            }
+        }
+
+        // That only finds the rotation up to the sign of the rotor.
+        // Another full turn in one of its planes flips that.
+        if (Self::from_torq(torq).0.rev() * self.0)[0] < S::ZERO {
+            let (u, v) = largest.unwrap_or_else(|| {
+                let u = col(0);
+                let v = (skew * u).normal().map(Vect::from).unwrap_or_else(|| {
+                    let axis = Vect::axis(if u[0].abs() < S::HALF { 0 } else { 1 }, S::ONE);
+                    axis.rej(u).normal_or_zero()
+                });
+                (u, v)
+            });
+            let plane: Vect<B, S> = u.wedge(v).bivector();
+            let turn = S::PI.mul(S::TWO);
+            torq += plane
+                * if torq.dot(plane) < S::ZERO {
+                    turn
+                } else {
+                    turn.neg()
+                };
+        }
+        torq
+    }
+
+    fn part(self, t: S) -> Self {
+        Self::from_torq(self.to_torq() * t)
+    }
+
+    fn inv(self) -> Self {
+        Self(self.0.rev())
+    }
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+
+    fn nlerp(self, other: Self, t: S) -> Self {
+        Self(self.0 + (other.0 - self.0) * t).renormalize()
+    }
+
+    fn aft(self, other: Self) -> Self {
+        Self(self.0 * other.0).renormalize()
+    }
+
+    fn apl(self, vect: Vect<N, S>) -> Vect<N, S> {
+        (self.0 * Multivector::from(vect) * self.0.rev()).vect()
+    }
+
+    fn normalize_bivector(vector: Self::Bivector) -> Option<Self::Axis> {
+        vector.normal()
+    }
+
+    fn mat(self) -> Mat<N, N, S> {
+        let cols: [Vect<N, S>; N] =
+            std::array::from_fn(|j| RotInner::apl(self, Vect::axis(j, S::ONE)));
+        Mat::from_cols(cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn test_torq() {
+        let torq = Vect([0.3, -1.2, 0.5, 2.2, -0.4, 0.9, 0.1, -0.7, 1.5, 0.2]);
+        let rot = Rot::<5, f64>::from_torq(torq);
+        assert_close(rot.to_torq(), torq, 1e-9);
+
+        let v = Vect([0.4, -2., 1.3, 0.7, -0.1]);
+        let half = rot.part(0.5);
+        assert_close(half.aft(half).apl(v), rot.apl(v), 1e-9);
+        assert_close(rot.inv().apl(rot.apl(v)), v, 1e-9);
+    }
+
+    #[test]
+    fn test_matches_rot4() {
+        let torq = Vect([0.3, -1.2, 0.5, 2.2, -0.4, 0.9]);
+        let rot4 = Rot::<4, f64>::from_torq(torq);
+        let rot5 = Rot::<5, f64>::from_torq(Vect([0.3, -1.2, 0.5, 0., 2.2, -0.4, 0., 0.9, 0., 0.]));
+        let v = Vect([0.4, -2., 1.3, 0.7]);
+        let Vect([x, y, z, w, _]) = rot5.apl(Vect([0.4, -2., 1.3, 0.7, 0.]));
+        assert_close(rot4.apl(v), Vect([x, y, z, w]), 1e-9);
+    }
+}
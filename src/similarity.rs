@@ -0,0 +1,182 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Mat, Pnt, Rig, Vect,
+    ops::{Apl, BefAft},
+    rotor::*,
+    traits::*,
+};
+
+/// A rigid motion together with a uniform scale, which is applied before the rotation.
+#[derive(Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(
+        serialize = "
+            S: Serialize,
+            [S; N]: Serialize,
+            Bivector<N, S>: Serialize,
+        ",
+        deserialize = "
+            S: Deserialize<'de>,
+            [S; N]: Deserialize<'de>,
+            Bivector<N, S>: Deserialize<'de>,
+        ",
+    ))
+)]
+pub struct Sim<const N: usize, S: Field>
+where
+    (): RotDim<N>,
+{
+    pub trans: Vect<N, S>,
+    pub rot: Rot<N, S>,
+    pub scale: S,
+}
+
+impl<const N: usize, S: Field> Default for Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    fn default() -> Self {
+        Self::IDENT
+    }
+}
+
+impl<const N: usize, S: Field> Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    pub const IDENT: Self = Self {
+        trans: Vect::ZERO,
+        rot: Rot::IDENT,
+        scale: S::ONE,
+    };
+
+    pub fn new(trans: Vect<N, S>, rot: Rot<N, S>, scale: S) -> Self {
+        Self { trans, rot, scale }
+    }
+
+    pub fn rot(rot: Rot<N, S>) -> Self {
+        Self { rot, ..Self::IDENT }
+    }
+
+    pub fn trans(trans: Vect<N, S>) -> Self {
+        Self {
+            trans,
+            ..Self::IDENT
+        }
+    }
+
+    pub fn scale(scale: S) -> Self {
+        Self {
+            scale,
+            ..Self::IDENT
+        }
+    }
+
+    pub fn inv(self) -> Self {
+        let rot = self.rot.inv();
+        let scale = S::ONE.div(self.scale);
+        Self {
+            rot,
+            scale,
+            trans: rot.apl(-self.trans) * scale,
+        }
+    }
+}
+
+impl<const N: usize, S: Field> From<Rig<N, S>> for Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    fn from(value: Rig<N, S>) -> Self {
+        Self::new(value.trans, value.rot, S::ONE)
+    }
+}
+
+impl<const N: usize, S: Field> From<Rot<N, S>> for Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    fn from(value: Rot<N, S>) -> Self {
+        Self::rot(value)
+    }
+}
+
+/// Rotated and scaled, but not translated, which only [`Pnt`]s are.
+impl<const N: usize, S: Field> Apl<Vect<N, S>> for Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    type Output = Vect<N, S>;
+
+    fn apl(self, other: Vect<N, S>) -> Vect<N, S> {
+        self.rot.apl(other) * self.scale
+    }
+}
+
+impl<const N: usize, S: Field> Apl<Pnt<N, S>> for Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    type Output = Pnt<N, S>;
+
+    fn apl(self, other: Pnt<N, S>) -> Pnt<N, S> {
+        Pnt::ORIGIN + self.apl(other - Pnt::ORIGIN) + self.trans
+    }
+}
+
+impl<const N: usize, S: Field> BefAft for Sim<N, S>
+where
+    (): RotDim<N>,
+{
+    fn aft(self, other: Self) -> Self {
+        Self {
+            rot: self.rot.aft(other.rot),
+            trans: self.rot.apl(other.trans) * self.scale + self.trans,
+            scale: self.scale.mul(other.scale),
+        }
+    }
+}
+
+impl<S: Field> Sim<3, S> {
+    pub fn to_hmat(self) -> Mat<4, 4, S> {
+        Mat::affine(self.rot.mat() * self.scale, self.trans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sim() {
+        let a = Sim::<3, f64>::new(
+            Vect([1., 2., 3.]),
+            Rot::from_torq(Vect([0.3, -1.2, 0.5])),
+            2.5,
+        );
+        let b = Sim::new(
+            Vect([-1., 0.5, 2.]),
+            Rot::from_torq(Vect([-1.1, 0.2, 0.8])),
+            0.4,
+        );
+        let p = Pnt([0.4, -2., 1.3]);
+        let v = Vect([1., -1., 0.5]);
+        assert!((a.aft(b).apl(p) - a.apl(b.apl(p))).magn() < 1e-9);
+        assert!((a.aft(a.inv()).apl(p) - p).magn() < 1e-9);
+        assert!((a.inv().aft(a).apl(p) - p).magn() < 1e-9);
+        assert!((a.apl(v) - (a.apl(p + v) - a.apl(p))).magn() < 1e-9);
+
+        let image = Pnt::from_homogeneous(a.to_hmat() * Vect::from(p)).unwrap();
+        assert!((image - a.apl(p)).magn() < 1e-9);
+
+        let rig = Rig::new(Vect([1., 2., 3.]), Rot::from_torq(Vect([0.3, -1.2, 0.5])));
+        let sim = Sim::from(rig);
+        assert_eq!(sim.scale, 1.);
+        assert!((sim.apl(p) - rig.apl(p)).magn() < 1e-9);
+        assert!((sim.inv().apl(p) - rig.inv().apl(p)).magn() < 1e-9);
+    }
+}
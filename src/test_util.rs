@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+use crate::{Mat, Vect};
+
+/// Values whose entries tests compare within a tolerance.
+pub(crate) trait Entries: Copy + Debug {
+    fn entries(self) -> Vec<f64>;
+}
+
+impl<const N: usize, const M: usize> Entries for Mat<N, M, f64> {
+    fn entries(self) -> Vec<f64> {
+        self.0.into_iter().flatten().collect()
+    }
+}
+
+impl<const N: usize> Entries for Vect<N, f64> {
+    fn entries(self) -> Vec<f64> {
+        self.0.to_vec()
+    }
+}
+
+/// Panics, showing both, unless each entry of `a` is within `tolerance` of that of `b`.
+pub(crate) fn assert_close<T: Entries>(a: T, b: T, tolerance: f64) {
+    let close = a
+        .entries()
+        .iter()
+        .zip(b.entries())
+        .all(|(x, y)| (x - y).abs() < tolerance);
+    assert!(close, "{a:?} != {b:?}");
+}
@@ -94,6 +94,8 @@ macro_rules! float_impls {
 
             const INFINITY: Self = Self::INFINITY;
 
+            const EPSILON: Self = Self::EPSILON;
+
             fn sqrt(self) -> Self {self.sqrt()}
         
             fn exp(self) -> Self {self.exp()}
@@ -55,6 +55,7 @@ pub trait Field: Ring {
     const PI: Self;
     const SQRT_2: Self;
     const INFINITY: Self;
+    const EPSILON: Self;
 
     fn sqrt(self) -> Self;
 